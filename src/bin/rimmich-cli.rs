@@ -0,0 +1,237 @@
+use clap::Parser;
+use rimmich::core::{
+    BatchControl, ImmichClient, UploadEvent, UploadJob, collect_media_files, find_or_create_album,
+    init_logger, load_config, run_upload, set_bandwidth, validate_server_url,
+};
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+/// rImmich 命令行上传工具，适用于 cron 或 SSH 等无桌面环境
+#[derive(Debug, Parser)]
+#[command(
+    name = "rimmich-cli",
+    version,
+    about = "Upload photos and videos to Immich"
+)]
+struct Args {
+    /// 要上传的文件或目录，目录会被递归扫描
+    #[arg(required = true, help = "Files or directories to upload")]
+    paths: Vec<PathBuf>,
+
+    /// 使用的用户名，默认为配置中的当前用户
+    #[arg(short, long, help = "User to upload as (defaults to current user)")]
+    user: Option<String>,
+
+    /// 覆盖用户所属服务器配置中的地址
+    #[arg(short, long, help = "Override the server URL of the user's profile")]
+    server: Option<String>,
+
+    /// 覆盖配置中的并发数
+    #[arg(short, long, help = "Override the concurrency from config (1-16)")]
+    concurrency: Option<u8>,
+
+    /// 覆盖配置中的上传限速，0 表示不限速
+    #[arg(
+        long,
+        help = "Override the upload rate limit in KB/s (0 for unlimited)"
+    )]
+    limit_kbps: Option<u32>,
+
+    /// 上传后加入的相册，不存在时自动创建
+    #[arg(
+        short,
+        long,
+        help = "Add uploaded files to this album (created if missing)"
+    )]
+    album: Option<String>,
+
+    /// 出错时继续上传剩余文件
+    #[arg(long, help = "Keep uploading remaining files after a failure")]
+    continue_on_error: bool,
+
+    /// 忽略本地上传记录，重新上传所有文件
+    #[arg(
+        long,
+        help = "Upload again even if the local record says a file was already uploaded"
+    )]
+    force: bool,
+
+    /// 覆盖配置中的日志级别
+    #[arg(long, help = "Override the log level from config")]
+    log_level: Option<String>,
+}
+
+#[tokio::main]
+async fn main() -> ExitCode {
+    let args = Args::parse();
+
+    let config = match load_config() {
+        Ok(config) => config,
+        Err(e) => {
+            eprintln!("Failed to load config: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let log_level = args.log_level.as_deref().unwrap_or(&config.log_level);
+    init_logger(log_level).unwrap_or_else(|e| {
+        eprintln!("Failed to initialize logger: {}", e);
+    });
+
+    if let Some(url) = &args.server
+        && let Err(e) = validate_server_url(url)
+    {
+        eprintln!("Invalid server URL {:?}: {:#}", url, e);
+        return ExitCode::FAILURE;
+    }
+
+    let username = args.user.unwrap_or_else(|| config.current_user.clone());
+    let Some(user_info) = config.users.get(&username) else {
+        eprintln!("API Key not found for user: {:?}", username);
+        return ExitCode::FAILURE;
+    };
+
+    let concurrency = args.concurrency.unwrap_or(config.concurrency);
+    if !(1..=16).contains(&concurrency) {
+        eprintln!("Concurrency must be between 1 and 16");
+        return ExitCode::FAILURE;
+    }
+
+    let mut bandwidth = config.bandwidth.clone();
+    if let Some(limit_kbps) = args.limit_kbps {
+        // 命令行指定的限速全天生效
+        bandwidth.limit_kbps = limit_kbps;
+        bandwidth.unlimited_window = None;
+    }
+    set_bandwidth(&bandwidth);
+
+    let scan = collect_media_files(&args.paths);
+    println!(
+        "Found {} files ({} ignored)",
+        scan.files.len(),
+        scan.ignored
+    );
+    if scan.files.is_empty() {
+        return ExitCode::SUCCESS;
+    }
+
+    let Some(mut server) = config.server_for_user(&username).cloned() else {
+        eprintln!("Server profile not found for user: {:?}", username);
+        return ExitCode::FAILURE;
+    };
+    if let Some(url) = args.server {
+        server.url = url.trim().trim_end_matches('/').to_string();
+    }
+    let api_key = match user_info.require_api_key() {
+        Ok(api_key) => api_key,
+        Err(e) => {
+            eprintln!("Cannot upload for user {:?}: {:#}", username, e);
+            return ExitCode::FAILURE;
+        }
+    };
+    let client = match ImmichClient::new(&server, api_key) {
+        Ok(client) => client,
+        Err(e) => {
+            eprintln!("Failed to create client for {}: {:#}", server.url, e);
+            return ExitCode::FAILURE;
+        }
+    };
+    let album_id = match args.album.as_deref().map(str::trim) {
+        Some(album_name) if !album_name.is_empty() => {
+            match find_or_create_album(&client, album_name).await {
+                Ok(album) => Some(album.id),
+                Err(e) => {
+                    eprintln!("Failed to prepare album {:?}: {}", album_name, e);
+                    return ExitCode::FAILURE;
+                }
+            }
+        }
+        _ => None,
+    };
+
+    let job = UploadJob {
+        client,
+        user: username.clone(),
+        concurrency: concurrency as usize,
+        files: scan.files,
+        continue_on_error: args.continue_on_error || config.continue_on_error,
+        retry: config.retry.clone(),
+        album_id,
+        force: args.force,
+        control: BatchControl::default(),
+    };
+
+    // Ctrl+C 取消上传，等待正在上传的文件中止后输出结果
+    let control = job.control.clone();
+    tokio::spawn(async move {
+        if tokio::signal::ctrl_c().await.is_ok() {
+            eprintln!("Cancelling upload...");
+            control.cancel();
+        }
+    });
+
+    let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+    let upload = tokio::spawn(run_upload(job, tx));
+
+    while let Some(event) = rx.recv().await {
+        match event {
+            UploadEvent::CheckingDuplicates { total } => {
+                println!("Checking {} files for duplicates...", total);
+            }
+            UploadEvent::Started {
+                total,
+                skipped,
+                already_uploaded,
+                ..
+            } => {
+                println!(
+                    "Uploading {} files ({} already uploaded, {} duplicates on server skipped)",
+                    total,
+                    already_uploaded,
+                    skipped - already_uploaded
+                );
+            }
+            UploadEvent::Skipped { .. } | UploadEvent::Progress { .. } => {}
+            UploadEvent::Uploaded {
+                file_name,
+                finished,
+                total,
+                ..
+            } => {
+                println!("[{}/{}] {}", finished, total, file_name);
+            }
+            UploadEvent::Failed {
+                file_name, error, ..
+            } => {
+                eprintln!("Failed to upload {}: {}", file_name, error);
+            }
+            UploadEvent::Cancelled { paths } => {
+                eprintln!("Upload cancelled, {} files not uploaded", paths.len());
+            }
+        }
+    }
+
+    match upload.await {
+        Ok(report) => {
+            println!(
+                "Done: {} uploaded, {} already uploaded, {} duplicates on server, {} failed",
+                report.uploaded.len(),
+                report.already_uploaded,
+                report.skipped - report.already_uploaded,
+                report.failed.len() + report.aborted.len()
+            );
+            for failure in &report.failed {
+                eprintln!("  {}: {}", failure.path.display(), failure.error);
+            }
+            if report.is_success() {
+                ExitCode::SUCCESS
+            } else {
+                ExitCode::FAILURE
+            }
+        }
+        Err(e) => {
+            eprintln!("Upload task failed: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
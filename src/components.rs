@@ -1,11 +1,15 @@
 // 模块声明
 mod helper;
+mod history;
 mod home;
 mod router;
 mod settings;
+mod watcher;
 
 // 公共导出
-pub use helper::get_i18n;
-pub use home::Home;
+pub use helper::{describe_connection, format_bytes, format_duration, get_i18n};
+pub use history::History;
+pub use home::{Home, SelectedFiles, UploadSession, use_upload_session};
 pub use router::{App, AppRoute};
 pub use settings::Settings;
+pub use watcher::{WatcherStatus, use_folder_watcher};
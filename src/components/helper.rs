@@ -1,7 +1,51 @@
-use crate::core::{AppConfig, I18n};
+use crate::core::{AppConfig, ConnectionInfo, I18n};
 use dioxus::prelude::*;
+use std::time::Duration;
 
 pub fn get_i18n(config: &Signal<AppConfig>) -> I18n {
     let language = config.read().language;
     I18n::new(language)
 }
+
+/// 生成连接测试结果的描述：服务器版本，以及账户名称、邮箱和存储用量
+pub fn describe_connection(i18n: &I18n, info: &ConnectionInfo) -> String {
+    let version = info.version.to_string();
+    let Some(account) = &info.account else {
+        return i18n.connection_ok(&version);
+    };
+    let used = format_bytes(account.quota_usage_in_bytes.unwrap_or(0));
+    let storage = match account.quota_size_in_bytes {
+        Some(quota) => format!("{} / {}", used, format_bytes(quota)),
+        None => i18n.storage_unlimited(&used),
+    };
+    i18n.connection_ok_with_account(&version, &account.name, &account.email, &storage)
+}
+
+/// 将字节数格式化为易读的字符串，例如 `1.5 MB`
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 5] = ["B", "KB", "MB", "GB", "TB"];
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{} {}", bytes, UNITS[unit])
+    } else {
+        format!("{:.1} {}", value, UNITS[unit])
+    }
+}
+
+/// 将时长格式化为 `1h 02m 03s` / `2m 03s` / `5s`
+pub fn format_duration(duration: Duration) -> String {
+    let secs = duration.as_secs();
+    let (hours, minutes, seconds) = (secs / 3600, secs % 3600 / 60, secs % 60);
+    if hours > 0 {
+        format!("{}h {:02}m {:02}s", hours, minutes, seconds)
+    } else if minutes > 0 {
+        format!("{}m {:02}s", minutes, seconds)
+    } else {
+        format!("{}s", seconds)
+    }
+}
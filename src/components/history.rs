@@ -0,0 +1,231 @@
+use crate::components::{AppRoute, SelectedFiles, UploadSession, format_bytes, get_i18n};
+use crate::core::{AppConfig, HistoryEntry, I18n, UploadOutcome, load_history};
+use chrono::Local;
+use dioxus::prelude::*;
+use dioxus_free_icons::Icon;
+use dioxus_free_icons::icons::fi_icons::FiArrowLeftCircle;
+use std::collections::HashSet;
+use std::path::PathBuf;
+
+/// 列表中最多显示的记录数，避免历史很长时页面卡顿
+const MAX_VISIBLE_ENTRIES: usize = 500;
+
+fn outcome_label(i18n: &I18n, outcome: UploadOutcome) -> &'static str {
+    match outcome {
+        UploadOutcome::Uploaded => i18n.outcome_uploaded(),
+        UploadOutcome::Duplicate => i18n.outcome_duplicate(),
+        UploadOutcome::Failed => i18n.outcome_failed(),
+    }
+}
+
+/// 最近一次上传仍然失败、且本地文件还在的路径
+///
+/// 需要传入未经筛选的全部记录，否则筛选掉的成功记录会让之前的失败看起来仍是最新结果
+fn failed_paths(entries: &[HistoryEntry]) -> HashSet<PathBuf> {
+    let mut seen = HashSet::new();
+    // 记录按时间倒序排列，第一次出现的就是该文件最近的结果
+    entries
+        .iter()
+        .filter(|entry| seen.insert(entry.path.clone()))
+        .filter(|entry| entry.outcome == UploadOutcome::Failed && entry.path.exists())
+        .map(|entry| entry.path.clone())
+        .collect()
+}
+
+#[component]
+pub fn History() -> Element {
+    let nav = use_navigator();
+    let config = use_context::<Signal<AppConfig>>();
+    let SelectedFiles(mut selected_files) = use_context::<SelectedFiles>();
+    let UploadSession {
+        mut last_report, ..
+    } = use_context::<UploadSession>();
+    let i18n = get_i18n(&config);
+    let mut query = use_signal(String::new);
+    let mut outcome = use_signal(|| None::<UploadOutcome>);
+    let history = use_resource(|| async {
+        tokio::task::spawn_blocking(load_history)
+            .await
+            .map_err(anyhow::Error::from)
+            .and_then(|result| result)
+            .map_err(|e| {
+                tracing::error!("Failed to load upload history: {}", e);
+                e.to_string()
+            })
+    });
+
+    // 将文件加入主页的待上传列表并返回主页
+    let mut reopen = move |paths: Vec<PathBuf>| {
+        tracing::info!("Re-opening {} files from history", paths.len());
+        selected_files.with_mut(|files| {
+            for path in paths {
+                if !files.contains(&path) {
+                    files.push(path);
+                }
+            }
+        });
+        // 主页显示上次的上传结果时看不到文件列表
+        last_report.set(None);
+        nav.push(AppRoute::Home {});
+    };
+
+    let entries = match &*history.read() {
+        Some(Ok(entries)) => entries.clone(),
+        Some(Err(e)) => {
+            return rsx! {
+                link { rel: "stylesheet", href: asset!("/assets/home.css") }
+                HistoryHeader { config }
+                div { class: "status-text", "{i18n.load_history_failed(e)}" }
+            };
+        }
+        None => Vec::new(),
+    };
+    let still_failed = failed_paths(&entries);
+    let query_text = query.read().trim().to_lowercase();
+    let filtered: Vec<HistoryEntry> = entries
+        .into_iter()
+        .filter(|entry| {
+            outcome
+                .read()
+                .is_none_or(|outcome| entry.outcome == outcome)
+        })
+        .filter(|entry| {
+            query_text.is_empty()
+                || entry
+                    .path
+                    .to_string_lossy()
+                    .to_lowercase()
+                    .contains(&query_text)
+        })
+        .collect();
+    let mut seen = HashSet::new();
+    let reopen_paths: Vec<PathBuf> = filtered
+        .iter()
+        .filter(|entry| still_failed.contains(&entry.path) && seen.insert(&entry.path))
+        .map(|entry| entry.path.clone())
+        .collect();
+
+    rsx! {
+        link { rel: "stylesheet", href: asset!("/assets/home.css") }
+        HistoryHeader { config }
+
+        div { class: "user-group history-filters",
+            input {
+                placeholder: "{i18n.search_history()}",
+                value: "{query}",
+                oninput: move |evt| query.set(evt.value()),
+            }
+            select {
+                onchange: move |evt| {
+                    outcome.set(match evt.value().as_str() {
+                        "uploaded" => Some(UploadOutcome::Uploaded),
+                        "duplicate" => Some(UploadOutcome::Duplicate),
+                        "failed" => Some(UploadOutcome::Failed),
+                        _ => None,
+                    });
+                },
+                option { value: "all", "{i18n.all_outcomes()}" }
+                option { value: "uploaded", "{i18n.outcome_uploaded()}" }
+                option { value: "duplicate", "{i18n.outcome_duplicate()}" }
+                option { value: "failed", "{i18n.outcome_failed()}" }
+            }
+        }
+
+        div { class: "files-list-container history-list",
+            h4 { "{i18n.history_count(filtered.len())}" }
+            if filtered.is_empty() {
+                div { class: "no-files-selected", "{i18n.no_history()}" }
+            } else {
+                ul {
+                    for entry in filtered.into_iter().take(MAX_VISIBLE_ENTRIES) {
+                        HistoryItem {
+                            config,
+                            can_reopen: entry.outcome == UploadOutcome::Failed
+                                && still_failed.contains(&entry.path),
+                            entry,
+                            on_reopen: move |path| reopen(vec![path]),
+                        }
+                    }
+                }
+            }
+        }
+
+        if !reopen_paths.is_empty() {
+            div { class: "button-row",
+                button {
+                    onclick: {
+                        let reopen_paths = reopen_paths.clone();
+                        move |_| reopen(reopen_paths.clone())
+                    },
+                    "{i18n.reopen_failed(reopen_paths.len())}"
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn HistoryHeader(config: Signal<AppConfig>) -> Element {
+    let nav = use_navigator();
+    let i18n = get_i18n(&config);
+
+    rsx! {
+        div { class: "header-row",
+            h2 { "{i18n.upload_history()}" }
+            button {
+                onclick: move |_| { nav.push(AppRoute::Home {}); },
+                Icon { class: "icon", icon: FiArrowLeftCircle }
+            }
+        }
+    }
+}
+
+#[component]
+fn HistoryItem(
+    config: Signal<AppConfig>,
+    entry: HistoryEntry,
+    /// 该文件最近一次上传仍然失败且文件还在
+    can_reopen: bool,
+    on_reopen: EventHandler<PathBuf>,
+) -> Element {
+    let i18n = get_i18n(&config);
+    let file_name = entry
+        .path
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let time = entry
+        .timestamp
+        .with_timezone(&Local)
+        .format("%Y-%m-%d %H:%M");
+    let outcome_class = match entry.outcome {
+        UploadOutcome::Uploaded => "uploaded",
+        UploadOutcome::Duplicate => "duplicate",
+        UploadOutcome::Failed => "failed",
+    };
+
+    rsx! {
+        li { class: "history-entry",
+            div { class: "history-main",
+                span { class: "file-name", title: "{entry.path.display()}", "{file_name}" }
+                span { class: "history-outcome {outcome_class}", "{outcome_label(&i18n, entry.outcome)}" }
+            }
+            div { class: "history-details",
+                "{time} · {entry.user} · {entry.server} · {format_bytes(entry.size)}"
+            }
+            if let Some(error) = &entry.error {
+                div { class: "file-error", title: "{error}", "{i18n.upload_error(error)}" }
+            }
+            if can_reopen {
+                button {
+                    class: "history-reopen",
+                    onclick: {
+                        let path = entry.path.clone();
+                        move |_| on_reopen.call(path.clone())
+                    },
+                    "{i18n.reopen()}"
+                }
+            }
+        }
+    }
+}
@@ -1,33 +1,107 @@
+mod album;
 mod file_list;
 mod icons;
+mod progress;
+mod resume;
+mod summary;
 mod upload;
 
+use album::*;
 use file_list::*;
 use icons::*;
+use progress::*;
+use resume::*;
+use summary::*;
 use upload::*;
 
-use crate::components::{AppRoute, get_i18n};
-use crate::core::AppConfig;
+pub use upload::{UploadSession, use_upload_session};
+
+use crate::components::{AppRoute, WatcherStatus, get_i18n};
+use crate::core::{AppConfig, UploadQueue, load_queue, save_config};
 use dioxus::prelude::*;
-use rfd::FileHandle;
+use std::path::PathBuf;
+
+/// 待上传的文件列表，通过 context 共享，切换页面后仍然保留
+#[derive(Clone, Copy)]
+pub struct SelectedFiles(pub Signal<Vec<PathBuf>>);
 
 #[component]
 pub fn Home() -> Element {
     let nav = use_navigator();
-    let config = use_context::<Signal<AppConfig>>();
+    let mut config = use_context::<Signal<AppConfig>>();
+    let WatcherStatus(watch_status) = use_context::<WatcherStatus>();
     let mut selected_user = use_signal(|| config.read().current_user.clone());
-    let server_url = config.read().server_url.clone();
-    let mut status = use_signal(|| {
-        let i18n = get_i18n(&config);
-        i18n.ready().to_string()
+    let server_url = config
+        .read()
+        .current_server()
+        .map(|server| server.url.clone())
+        .unwrap_or_default();
+    let SelectedFiles(mut selected_files) = use_context::<SelectedFiles>();
+    let session = use_context::<UploadSession>();
+    let UploadSession {
+        batch_state,
+        mut status,
+        last_report,
+        progress,
+    } = session;
+    // 没有进行中的批次时才重置状态文字，上传中返回主页应继续显示上传进度
+    use_hook(move || {
+        if !batch_state.peek().is_active() && last_report.peek().is_none() {
+            let i18n = get_i18n(&config);
+            status.set(match selected_files.peek().len() {
+                0 => i18n.ready().to_string(),
+                count => i18n.files_selected(count),
+            });
+        }
+    });
+    let mut album_name = use_signal(String::new);
+    let retry_upload = move |files: Vec<PathBuf>| {
+        selected_files.set(files);
+        start_upload(
+            session,
+            selected_user.read().clone(),
+            album_name.read().clone(),
+            false,
+        );
+    };
+    let force_upload = use_signal(|| false);
+    // 上次未完成的上传队列，上传进行中时磁盘上的队列属于当前批次，不能再提示继续
+    let mut pending_queue = use_signal(|| {
+        if batch_state.peek().is_active() {
+            return None;
+        }
+        load_queue().unwrap_or_else(|e| {
+            tracing::warn!("Failed to load upload queue: {}", e);
+            None
+        })
+    });
+    let resume_upload = move |queue: UploadQueue| {
+        pending_queue.set(None);
+        selected_user.set(queue.user.clone());
+        album_name.set(queue.album.clone().unwrap_or_default());
+        selected_files.set(queue.remaining());
+        start_upload(session, queue.user, queue.album.unwrap_or_default(), false);
+    };
+    // 开始新的上传后旧队列会被覆盖，不再提示
+    use_effect(move || {
+        if batch_state.read().is_active() {
+            pending_queue.set(None);
+        }
     });
-    let selected_files = use_signal(|| Vec::<FileHandle>::new());
-    let is_uploading = use_signal(|| false);
 
     rsx! {
         link { rel: "stylesheet", href: asset!("/assets/home.css") }
 
         div { class: "header-row",
+            button {
+                class: "header-left",
+                title: {
+                    let i18n = get_i18n(&config);
+                    i18n.upload_history()
+                },
+                onclick: move |_| { nav.push(AppRoute::History {}); },
+                HistoryIcon {}
+            }
             h2 {
                 {
                     let i18n = get_i18n(&config);
@@ -40,9 +114,35 @@ pub fn Home() -> Element {
             }
         }
 
+        // 上传中切换服务器或账号会让界面与正在上传的批次不一致
+        ProfileSelector {
+            config,
+            disabled: batch_state.read().is_active(),
+            on_change: move |profile: String| {
+                let i18n = get_i18n(&config);
+                let mut temp_config = config.read().clone();
+                temp_config.current_profile = profile.clone();
+                let users = temp_config.profile_users(&profile);
+                if !users.contains(&temp_config.current_user) {
+                    temp_config.current_user = users.first().cloned().unwrap_or_default();
+                }
+
+                if let Err(e) = save_config(&mut temp_config) {
+                    tracing::error!("Failed to switch server profile: {}", e);
+                    status.set(i18n.save_failed(&e.to_string()));
+                } else {
+                    tracing::info!("Switched to server profile: {}", profile);
+                    selected_user.set(temp_config.current_user.clone());
+                    config.set(temp_config);
+                    status.set(i18n.switched_to_profile(&profile));
+                }
+            }
+        }
+
         UserSelector {
             selected_user,
             config,
+            disabled: batch_state.read().is_active(),
             on_change: move |username: String| {
                 let i18n = get_i18n(&config);
                 selected_user.set(username.clone());
@@ -60,17 +160,47 @@ pub fn Home() -> Element {
             p { "{server_url}" }
         }
 
-        FileList { selected_files }
+        AlbumSelector { config, selected_user, album_name, batch_state }
+
+        if !batch_state.read().is_active() {
+            ResumePrompt {
+                config,
+                batch_state,
+                status,
+                pending_queue,
+                on_resume: resume_upload
+            }
+        }
+
+        if batch_state.read().is_active() {
+            UploadProgress { config, progress, batch_state }
+        } else if last_report.read().is_some() {
+            UploadSummary {
+                config,
+                batch_state,
+                last_report,
+                on_retry: retry_upload
+            }
+        } else {
+            FileList { selected_files, status }
+        }
+
+        UploadOptions { config, batch_state, force_upload }
 
         UploadButtons {
             config,
             selected_user,
+            album_name,
             selected_files,
-            is_uploading,
-            status
+            session,
+            force_upload
         }
 
         div { class: "status-text", "{status}" }
+
+        if !watch_status.read().is_empty() {
+            div { class: "watch-status", "{watch_status}" }
+        }
     }
 }
 
@@ -78,31 +208,31 @@ pub fn Home() -> Element {
 fn UserSelector(
     selected_user: Signal<String>,
     config: Signal<AppConfig>,
+    disabled: bool,
     on_change: EventHandler<String>,
 ) -> Element {
     let i18n = get_i18n(&config);
+    let users = {
+        let config = config.read();
+        config.profile_users(&config.current_profile)
+    };
 
     rsx! {
         div { class: "user-group",
             label { "{i18n.select_upload_account()}" }
             select {
+                disabled,
                 value: selected_user.read().clone(),
                 onchange: move |evt| on_change.call(evt.value()),
 
-                if config.read().users.is_empty() {
+                if users.is_empty() {
                     option { value: "", "{i18n.add_account_in_settings()}" }
                 } else {
-                    {
-                        let mut users: Vec<_> = config.read().users.keys().cloned().collect();
-                        users.sort();
-                        rsx! {
-                            for username in users {
-                                option {
-                                    value: "{username}",
-                                    selected: *selected_user.read() == *username,
-                                    "{username}"
-                                }
-                            }
+                    for username in users {
+                        option {
+                            value: "{username}",
+                            selected: *selected_user.read() == *username,
+                            "{username}"
                         }
                     }
                 }
@@ -110,3 +240,32 @@ fn UserSelector(
         }
     }
 }
+
+#[component]
+fn ProfileSelector(
+    config: Signal<AppConfig>,
+    disabled: bool,
+    on_change: EventHandler<String>,
+) -> Element {
+    let i18n = get_i18n(&config);
+    let current_profile = config.read().current_profile.clone();
+    let profiles: Vec<_> = config.read().profiles.keys().cloned().collect();
+
+    rsx! {
+        div { class: "user-group",
+            label { "{i18n.server_profile()}" }
+            select {
+                disabled,
+                value: current_profile.clone(),
+                onchange: move |evt| on_change.call(evt.value()),
+                for profile in profiles {
+                    option {
+                        value: "{profile}",
+                        selected: current_profile == profile,
+                        "{profile}"
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,60 @@
+use crate::components::get_i18n;
+use crate::core::{AppConfig, BatchState, ImmichClient};
+use dioxus::prelude::*;
+
+/// 相册选择：可从已有相册中选择，也可输入新相册名称，上传时自动创建
+#[component]
+pub fn AlbumSelector(
+    config: Signal<AppConfig>,
+    selected_user: Signal<String>,
+    album_name: Signal<String>,
+    batch_state: Signal<BatchState>,
+) -> Element {
+    let i18n = get_i18n(&config);
+    // 只依赖所选用户的服务器配置和 API Key，保存其他设置时不会重新创建客户端和获取相册
+    let connection = use_memo(move || {
+        let username = selected_user.read();
+        let current_config = config.read();
+        let server = current_config.server_for_user(&username)?.clone();
+        let api_key = current_config
+            .users
+            .get(&*username)?
+            .require_api_key()
+            .inspect_err(|e| tracing::debug!("Not loading albums for {}: {}", username, e))
+            .ok()?
+            .to_string();
+        Some((server, api_key))
+    });
+    let albums = use_resource(move || async move {
+        let Some((server, api_key)) = connection.read().clone() else {
+            return Vec::new();
+        };
+        let albums = match ImmichClient::new(&server, &api_key) {
+            Ok(client) => client.list_albums().await.map_err(anyhow::Error::from),
+            Err(e) => Err(e),
+        };
+        albums.unwrap_or_else(|e| {
+            tracing::warn!("Failed to load albums: {}", e);
+            Vec::new()
+        })
+    });
+    let albums = albums.read().clone().unwrap_or_default();
+
+    rsx! {
+        div { class: "user-group album-group",
+            label { "{i18n.album()}" }
+            input {
+                list: "album-options",
+                placeholder: "{i18n.album_placeholder()}",
+                disabled: batch_state.read().is_active(),
+                value: "{album_name}",
+                oninput: move |evt| album_name.set(evt.value()),
+            }
+            datalist { id: "album-options",
+                for album in albums {
+                    option { value: "{album.album_name}", "{i18n.album_asset_count(album.asset_count as usize)}" }
+                }
+            }
+        }
+    }
+}
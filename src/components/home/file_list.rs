@@ -1,28 +1,149 @@
-use crate::components::get_i18n;
+use super::icons::{ImageIcon, VideoIcon};
+use crate::components::{format_bytes, get_i18n};
 use crate::core::*;
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use chrono::Local;
+use dioxus::html::HasFileData;
 use dioxus::prelude::*;
-use rfd::FileHandle;
+use std::collections::{BTreeSet, HashSet};
+use std::path::{Path, PathBuf};
+
+/// 每行显示的缩略图数量，窗口大小固定
+const COLUMNS: usize = 4;
+/// 每行的高度（像素），需与样式表中的 .thumbnail 高度一致
+const ROW_HEIGHT: f64 = 150.0;
+/// 列表可见区域的高度（像素）
+const VIEWPORT_HEIGHT: f64 = 420.0;
+/// 可见区域上下额外渲染的行数，减少快速滚动时的空白
+const OVERSCAN_ROWS: usize = 2;
+
+/// 列表的显示筛选，不影响实际上传的文件
+#[derive(Debug, Clone, PartialEq)]
+enum FileFilter {
+    All,
+    Media(MediaType),
+    Extension(String),
+}
+
+impl FileFilter {
+    fn parse(value: &str) -> Self {
+        match value {
+            "image" => Self::Media(MediaType::Image),
+            "video" => Self::Media(MediaType::Video),
+            _ => match value.strip_prefix("ext:") {
+                Some(ext) => Self::Extension(ext.to_string()),
+                None => Self::All,
+            },
+        }
+    }
+
+    fn matches(&self, path: &Path) -> bool {
+        match self {
+            Self::All => true,
+            Self::Media(media) => media_type(path) == *media,
+            Self::Extension(ext) => extension_of(path) == *ext,
+        }
+    }
+}
+
+fn sort_value(key: SortKey) -> &'static str {
+    match key {
+        SortKey::Name => "name",
+        SortKey::Date => "date",
+        SortKey::Size => "size",
+        SortKey::Type => "type",
+    }
+}
 
 #[component]
-pub fn FileList(selected_files: Signal<Vec<FileHandle>>) -> Element {
+pub fn FileList(selected_files: Signal<Vec<PathBuf>>, mut status: Signal<String>) -> Element {
     let config = use_context::<Signal<AppConfig>>();
     let i18n = get_i18n(&config);
+    let mut dragging = use_signal(|| false);
+    let mut scroll_top = use_signal(|| 0.0);
+    let filter = use_signal(|| FileFilter::All);
 
     rsx! {
-        div { class: "files-list-container",
+        div {
+            class: if *dragging.read() { "files-list-container drop-zone dragging" } else { "files-list-container drop-zone" },
+            onscroll: move |evt| scroll_top.set(evt.data().scroll_top()),
+            ondragover: move |evt| {
+                evt.prevent_default();
+                dragging.set(true);
+            },
+            ondragleave: move |_| dragging.set(false),
+            ondrop: move |evt| async move {
+                evt.prevent_default();
+                dragging.set(false);
+                let paths: Vec<PathBuf> = evt.files().iter().map(|file| file.path()).collect();
+                if paths.is_empty() {
+                    return;
+                }
+                let i18n = get_i18n(&config);
+                status.set(i18n.scanning_folders().to_string());
+                // 文件夹会被递归展开
+                match tokio::task::spawn_blocking(move || collect_media_files(&paths)).await {
+                    Ok(scan) => {
+                        let mut added = 0;
+                        selected_files.with_mut(|files| {
+                            let mut existing: HashSet<PathBuf> = files.iter().cloned().collect();
+                            for path in scan.files {
+                                if existing.insert(path.clone()) {
+                                    files.push(path);
+                                    added += 1;
+                                }
+                            }
+                        });
+                        tracing::info!("Dropped files: {} added, {} ignored", added, scan.ignored);
+                        status.set(i18n.files_added(added, scan.ignored));
+                    }
+                    Err(e) => tracing::error!("Dropped files scan task failed: {}", e),
+                }
+            },
             if selected_files.read().is_empty() {
-                div { class: "no-files-selected", "{i18n.no_files_selected()}" }
+                div { class: "no-files-selected",
+                    "{i18n.no_files_selected()}"
+                    p { class: "drop-hint", "{i18n.drop_files_hint()}" }
+                }
             } else {
                 {
                     let file_list = selected_files.read();
+                    let current_filter = filter.read().clone();
+                    let shown: Vec<&PathBuf> = file_list
+                        .iter()
+                        .filter(|path| current_filter.matches(path))
+                        .collect();
                     let total_files = file_list.len();
+                    // 只渲染可见区域附近的行，其余行用容器高度占位
+                    let rows = shown.len().div_ceil(COLUMNS);
+                    let top = *scroll_top.read();
+                    let first_row = ((top / ROW_HEIGHT) as usize).saturating_sub(OVERSCAN_ROWS);
+                    let last_row = (((top + VIEWPORT_HEIGHT) / ROW_HEIGHT).ceil() as usize + OVERSCAN_ROWS)
+                        .min(rows);
+                    // 列表变短后滚动位置可能超出范围
+                    let end = (last_row * COLUMNS).min(shown.len());
+                    let visible = &shown[(first_row * COLUMNS).min(end)..end];
                     rsx! {
-                        h4 { "{i18n.files_to_upload(total_files)}" }
-                        ul {
-                            for file in file_list.iter() {
-                                li {
-                                    span { "." }
-                                    "{file.path().display()}"
+                        h4 {
+                            if current_filter == FileFilter::All {
+                                "{i18n.files_to_upload(total_files)}"
+                            } else {
+                                "{i18n.showing_files(shown.len(), total_files)}"
+                            }
+                        }
+                        SelectionToolbar { selected_files, filter, status }
+                        div {
+                            class: "thumbnail-grid",
+                            style: "height: {rows as f64 * ROW_HEIGHT}px; padding-top: {first_row as f64 * ROW_HEIGHT}px;",
+                            for path in visible.iter() {
+                                Thumbnail {
+                                    key: "{path.display()}",
+                                    path: (*path).clone(),
+                                    on_remove: move |path: PathBuf| {
+                                        tracing::debug!("Removing {:?} from selection", path);
+                                        selected_files.with_mut(|files| files.retain(|file| *file != path));
+                                    },
                                 }
                             }
                         }
@@ -32,3 +153,178 @@ pub fn FileList(selected_files: Signal<Vec<FileHandle>>) -> Element {
         }
     }
 }
+
+/// 排序、筛选和统计信息
+#[component]
+fn SelectionToolbar(
+    selected_files: Signal<Vec<PathBuf>>,
+    mut filter: Signal<FileFilter>,
+    mut status: Signal<String>,
+) -> Element {
+    let config = use_context::<Signal<AppConfig>>();
+    let i18n = get_i18n(&config);
+    let mut sort_key = use_signal(|| None::<SortKey>);
+    let extensions: BTreeSet<String> = selected_files
+        .read()
+        .iter()
+        .map(|path| extension_of(path))
+        .collect();
+    // 统计需要读取每个文件的大小，在后台计算
+    let summary = use_resource(move || {
+        let files = selected_files.read().clone();
+        async move {
+            tokio::task::spawn_blocking(move || summarize_media(&files))
+                .await
+                .inspect_err(|e| tracing::error!("Selection summary task failed: {}", e))
+                .ok()
+        }
+    });
+    let summary = (*summary.read()).flatten();
+    let current_filter = filter.read().clone();
+
+    rsx! {
+        div { class: "selection-toolbar",
+            select {
+                onchange: move |evt| async move {
+                    let key = match evt.value().as_str() {
+                        "name" => SortKey::Name,
+                        "date" => SortKey::Date,
+                        "size" => SortKey::Size,
+                        "type" => SortKey::Type,
+                        _ => return,
+                    };
+                    let i18n = get_i18n(&config);
+                    let previous_status = status.read().clone();
+                    status.set(i18n.sorting_files().to_string());
+                    let mut files = selected_files.read().clone();
+                    match tokio::task::spawn_blocking(move || {
+                            sort_media_files(&mut files, key);
+                            files
+                        })
+                        .await
+                    {
+                        Ok(files) => {
+                            tracing::info!("Sorted {} selected files by {:?}", files.len(), key);
+                            sort_key.set(Some(key));
+                            selected_files.set(files);
+                        }
+                        Err(e) => tracing::error!("Sort task failed: {}", e),
+                    }
+                    status.set(previous_status);
+                },
+                option { value: "", disabled: true, selected: sort_key.read().is_none(), "{i18n.sort_files()}" }
+                for (key, label) in [
+                    (SortKey::Name, i18n.sort_by_name()),
+                    (SortKey::Date, i18n.sort_by_date()),
+                    (SortKey::Size, i18n.sort_by_size()),
+                    (SortKey::Type, i18n.sort_by_type()),
+                ] {
+                    option {
+                        value: sort_value(key),
+                        selected: *sort_key.read() == Some(key),
+                        "{label}"
+                    }
+                }
+            }
+            select {
+                onchange: move |evt| filter.set(FileFilter::parse(&evt.value())),
+                option { value: "all", selected: current_filter == FileFilter::All, "{i18n.all_media_types()}" }
+                option {
+                    value: "image",
+                    selected: current_filter == FileFilter::Media(MediaType::Image),
+                    "{i18n.images_only()}"
+                }
+                option {
+                    value: "video",
+                    selected: current_filter == FileFilter::Media(MediaType::Video),
+                    "{i18n.videos_only()}"
+                }
+                for ext in extensions.into_iter().filter(|ext| !ext.is_empty()) {
+                    option {
+                        value: "ext:{ext}",
+                        selected: current_filter == FileFilter::Extension(ext.clone()),
+                        ".{ext}"
+                    }
+                }
+            }
+            if current_filter != FileFilter::All {
+                button {
+                    onclick: move |_| {
+                        let filter = filter.read().clone();
+                        selected_files.with_mut(|files| files.retain(|path| !filter.matches(path)));
+                        tracing::info!("Removed files matching {:?} from selection", filter);
+                    },
+                    "{i18n.remove_shown()}"
+                }
+            }
+        }
+        if let Some(summary) = summary {
+            div { class: "selection-totals",
+                "{i18n.selection_totals(&format_bytes(summary.total_bytes), summary.images, summary.videos)}"
+            }
+        }
+    }
+}
+
+/// 单个文件的缩略图、大小和拍摄日期
+#[component]
+fn Thumbnail(path: PathBuf, on_remove: EventHandler<PathBuf>) -> Element {
+    let config = use_context::<Signal<AppConfig>>();
+    let i18n = get_i18n(&config);
+    let file_name = path
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let preview = use_resource({
+        let path = path.clone();
+        move || {
+            let path = path.clone();
+            async move {
+                tokio::task::spawn_blocking(move || load_preview(&path))
+                    .await
+                    .map_err(anyhow::Error::from)
+                    .and_then(|result| result)
+                    .map_err(|e| tracing::warn!("Failed to load preview: {:#}", e))
+                    .ok()
+            }
+        }
+    });
+    let preview = preview.read().clone().flatten();
+    let meta = preview.as_ref().map(|preview| {
+        format!(
+            "{} · {}",
+            format_bytes(preview.size),
+            preview.captured.with_timezone(&Local).format("%Y-%m-%d")
+        )
+    });
+
+    rsx! {
+        div { class: "thumbnail", title: "{path.display()}",
+            div { class: "thumbnail-image",
+                match preview.as_ref().and_then(|preview| preview.thumbnail.as_ref()) {
+                    Some(data) => rsx! {
+                        img { src: "data:image/jpeg;base64,{BASE64.encode(data)}" }
+                    },
+                    None if media_type(&path) == MediaType::Video => rsx! { VideoIcon {} },
+                    None => rsx! { ImageIcon {} },
+                }
+                if preview.as_ref().is_some_and(|preview| preview.has_sidecar) {
+                    span { class: "thumbnail-badge", title: "{i18n.sidecar_attached()}", "XMP" }
+                }
+                button {
+                    class: "thumbnail-remove",
+                    title: "{i18n.remove_file()}",
+                    onclick: {
+                        let path = path.clone();
+                        move |_| on_remove.call(path.clone())
+                    },
+                    "×"
+                }
+            }
+            span { class: "thumbnail-name", "{file_name}" }
+            if let Some(meta) = meta {
+                span { class: "thumbnail-meta", "{meta}" }
+            }
+        }
+    }
+}
@@ -1,6 +1,6 @@
 use dioxus::prelude::*;
 use dioxus_free_icons::Icon;
-use dioxus_free_icons::icons::fi_icons::FiSettings;
+use dioxus_free_icons::icons::fi_icons::{FiClock, FiFilm, FiImage, FiSettings};
 
 #[component]
 pub fn SettingsIcon() -> Element {
@@ -9,3 +9,27 @@ pub fn SettingsIcon() -> Element {
         icon: FiSettings
     })
 }
+
+#[component]
+pub fn HistoryIcon() -> Element {
+    rsx!(Icon {
+        class: "icon",
+        icon: FiClock
+    })
+}
+
+#[component]
+pub fn VideoIcon() -> Element {
+    rsx!(Icon {
+        class: "icon",
+        icon: FiFilm
+    })
+}
+
+#[component]
+pub fn ImageIcon() -> Element {
+    rsx!(Icon {
+        class: "icon",
+        icon: FiImage
+    })
+}
@@ -0,0 +1,60 @@
+use crate::components::{format_bytes, format_duration, get_i18n};
+use crate::core::{AppConfig, BatchProgress, BatchState};
+use dioxus::prelude::*;
+
+#[component]
+pub fn UploadProgress(
+    config: Signal<AppConfig>,
+    progress: Signal<BatchProgress>,
+    batch_state: Signal<BatchState>,
+) -> Element {
+    let i18n = get_i18n(&config);
+    let state_text = match *batch_state.read() {
+        BatchState::Paused(_) => Some(i18n.upload_paused()),
+        BatchState::Cancelling => Some(i18n.cancelling_upload()),
+        BatchState::Idle | BatchState::Running(_) => None,
+    };
+    let progress = progress.read();
+    let percent = progress.fraction() * 100.0;
+    let eta = progress
+        .eta()
+        .map(format_duration)
+        .unwrap_or_else(|| "--".to_string());
+    let summary = i18n.upload_progress(
+        &format_bytes(progress.sent_bytes()),
+        &format_bytes(progress.total_bytes),
+        &format_bytes(progress.throughput() as u64),
+        &eta,
+    );
+
+    rsx! {
+        div { class: "files-list-container upload-progress",
+            if let Some(state_text) = state_text {
+                div { class: "batch-state", "{state_text}" }
+            }
+            div { class: "progress-overall",
+                div { class: "progress-bar",
+                    div { class: "progress-fill", style: "width: {percent:.1}%" }
+                }
+                span { class: "progress-text", "{summary}" }
+            }
+
+            ul {
+                for (path, file) in progress.active.iter() {
+                    li { key: "{path.display()}",
+                        span { class: "file-name", "{file.file_name}" }
+                        div { class: "progress-bar",
+                            div {
+                                class: "progress-fill",
+                                style: format!("width: {:.1}%", file.fraction() * 100.0),
+                            }
+                        }
+                        span { class: "progress-text",
+                            "{format_bytes(file.sent)} / {format_bytes(file.total)}"
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,58 @@
+use crate::components::get_i18n;
+use crate::core::{AppConfig, BatchState, UploadQueue, clear_queue};
+use dioxus::prelude::*;
+
+/// 提示继续上次未完成的上传队列
+///
+/// 上传进行中不显示，此时磁盘上的队列属于正在上传的批次
+#[component]
+pub fn ResumePrompt(
+    config: Signal<AppConfig>,
+    batch_state: Signal<BatchState>,
+    status: Signal<String>,
+    pending_queue: Signal<Option<UploadQueue>>,
+    on_resume: EventHandler<UploadQueue>,
+) -> Element {
+    let i18n = get_i18n(&config);
+    let Some(queue) = pending_queue.read().clone() else {
+        return rsx! {};
+    };
+    let remaining = queue.remaining();
+
+    rsx! {
+        div { class: "resume-prompt",
+            span { "{i18n.resume_queue_prompt(remaining.len(), &queue.user)}" }
+            div { class: "button-row",
+                button {
+                    disabled: batch_state.read().is_active(),
+                    onclick: move |_| {
+                        tracing::info!(
+                            "Resuming upload queue: {} files for user {}",
+                            queue.remaining().len(),
+                            queue.user
+                        );
+                        on_resume.call(queue.clone());
+                    },
+                    "{i18n.resume_upload()}"
+                }
+                button {
+                    disabled: batch_state.read().is_active(),
+                    onclick: move |_| {
+                        let i18n = get_i18n(&config);
+                        match clear_queue() {
+                            Ok(()) => {
+                                tracing::info!("Discarded pending upload queue");
+                                pending_queue.set(None);
+                            }
+                            Err(e) => {
+                                tracing::error!("Failed to discard upload queue: {}", e);
+                                status.set(i18n.discard_queue_failed(&e.to_string()));
+                            }
+                        }
+                    },
+                    "{i18n.discard_queue()}"
+                }
+            }
+        }
+    }
+}
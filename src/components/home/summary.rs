@@ -0,0 +1,67 @@
+use crate::components::get_i18n;
+use crate::core::{AppConfig, BatchState, UploadReport};
+use dioxus::prelude::*;
+use std::path::PathBuf;
+
+/// 上传结果摘要，`on_retry` 由 Home 提供，重新上传开始后本组件会被卸载
+#[component]
+pub fn UploadSummary(
+    config: Signal<AppConfig>,
+    batch_state: Signal<BatchState>,
+    last_report: Signal<Option<UploadReport>>,
+    on_retry: EventHandler<Vec<PathBuf>>,
+) -> Element {
+    let i18n = get_i18n(&config);
+    let Some(report) = last_report.read().clone() else {
+        return rsx! {};
+    };
+    let retry_paths = report.retry_paths();
+
+    rsx! {
+        div { class: "files-list-container upload-summary",
+            h4 {
+                "{i18n.upload_summary(report.uploaded.len(), report.skipped, report.failed.len() + report.aborted.len())}"
+            }
+
+            if !report.failed.is_empty() {
+                h5 { "{i18n.failed_files()}" }
+                ul { class: "failed-list",
+                    for failure in report.failed.iter() {
+                        li {
+                            span { class: "file-name", "{failure.file_name}" }
+                            span {
+                                class: "file-error",
+                                title: "{failure.error}",
+                                "{i18n.upload_error(&failure.error)}"
+                            }
+                        }
+                    }
+                }
+            }
+
+            if !report.uploaded.is_empty() {
+                details {
+                    summary { "{i18n.uploaded_files()} ({report.uploaded.len()})" }
+                    ul {
+                        for path in report.uploaded.iter() {
+                            li { "{path.display()}" }
+                        }
+                    }
+                }
+            }
+
+            if !retry_paths.is_empty() {
+                div { class: "button-row",
+                    button {
+                        disabled: batch_state.read().is_active(),
+                        onclick: move |_| {
+                            tracing::info!("Retrying {} failed files", retry_paths.len());
+                            on_retry.call(retry_paths.clone());
+                        },
+                        "{i18n.retry_failed()}"
+                    }
+                }
+            }
+        }
+    }
+}
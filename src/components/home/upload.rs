@@ -1,31 +1,54 @@
 use crate::components::get_i18n;
-use crate::core::{AppConfig, upload_asst};
+use crate::core::{
+    AppConfig, BatchControl, BatchProgress, BatchState, ImmichClient, SUPPORTED_EXTENSIONS,
+    UploadEvent, UploadJob, UploadQueue, UploadReport, clear_queue, collect_media_files,
+    find_or_create_album, run_upload, save_config, save_queue,
+};
 use dioxus::prelude::*;
-use rfd::{AsyncFileDialog, FileHandle};
+use futures_util::StreamExt;
+use rfd::AsyncFileDialog;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+/// 上传队列写入磁盘的最短间隔，避免大批量上传时频繁写文件
+const QUEUE_SAVE_INTERVAL: Duration = Duration::from_secs(1);
 
 #[component]
 pub fn UploadButtons(
     config: Signal<AppConfig>,
     selected_user: Signal<String>,
-    selected_files: Signal<Vec<FileHandle>>,
-    is_uploading: Signal<bool>,
-    status: Signal<String>,
+    album_name: Signal<String>,
+    selected_files: Signal<Vec<PathBuf>>,
+    session: UploadSession,
+    mut force_upload: Signal<bool>,
 ) -> Element {
     let i18n = get_i18n(&config);
+    let UploadSession {
+        batch_state,
+        mut status,
+        mut last_report,
+        ..
+    } = session;
+
+    if batch_state.read().is_active() {
+        return rsx! {
+            BatchControls { config, batch_state }
+        };
+    }
 
     rsx! {
         div { class: "button-row",
             button {
-                disabled: "{is_uploading}",
                 onclick: move |_| async move {
                     let i18n = get_i18n(&config);
                     let files = AsyncFileDialog::new()
-                        .add_filter("media", &["jpg", "jpeg", "png", "heic", "webp", "mp4", "mov"])
+                        .add_filter("media", SUPPORTED_EXTENSIONS)
                         .set_title(i18n.select_photos_or_videos())
                         .pick_files()
                         .await;
                     if let Some(file_list) = files {
-                        selected_files.set(file_list);
+                        last_report.set(None);
+                        selected_files.set(file_list.iter().map(|file| file.path().to_path_buf()).collect());
                         status.set(i18n.files_selected(selected_files.read().len()));
                     }
                 },
@@ -33,120 +56,357 @@ pub fn UploadButtons(
             }
 
             button {
-                disabled: "{is_uploading} || {selected_files.read().is_empty()}",
+                onclick: move |_| async move {
+                    let i18n = get_i18n(&config);
+                    let folders = AsyncFileDialog::new()
+                        .set_title(i18n.select_folders())
+                        .pick_folders()
+                        .await;
+                    if let Some(folders) = folders {
+                        let folders: Vec<_> = folders.iter().map(|folder| folder.path().to_path_buf()).collect();
+                        status.set(i18n.scanning_folders().to_string());
+                        match tokio::task::spawn_blocking(move || collect_media_files(&folders)).await {
+                            Ok(scan) => {
+                                tracing::info!("Folder scan found {} files, ignored {}", scan.files.len(), scan.ignored);
+                                last_report.set(None);
+                                status.set(i18n.folder_scan_result(scan.files.len(), scan.ignored));
+                                selected_files.set(scan.files);
+                            }
+                            Err(e) => tracing::error!("Folder scan task failed: {}", e),
+                        }
+                    }
+                },
+                "{i18n.select_folder()}"
+            }
+
+            button {
+                disabled: selected_files.read().is_empty(),
                 onclick: move |_| {
                     let i18n = get_i18n(&config);
                     selected_files.set(Vec::new());
+                    last_report.set(None);
                     status.set(i18n.selection_cleared().to_string());
                 },
                 "{i18n.clear()}"
             }
 
             button {
-                disabled: "{is_uploading} || {selected_files.read().is_empty()}",
-                onclick: move |_| async move {
-                    handle_upload(config, selected_user, selected_files, is_uploading, status).await;
+                disabled: selected_files.read().is_empty(),
+                onclick: move |_| {
+                    let force = *force_upload.read();
+                    start_upload(session, selected_user.read().clone(), album_name.read().clone(), force);
+                    // 强制上传只对本批次生效
+                    force_upload.set(false);
+                },
+                "{i18n.start_upload()}"
+            }
+        }
+    }
+}
+
+/// 上传进行中的暂停、继续和取消按钮
+#[component]
+fn BatchControls(config: Signal<AppConfig>, mut batch_state: Signal<BatchState>) -> Element {
+    let i18n = get_i18n(&config);
+    let state = batch_state.read().clone();
+
+    rsx! {
+        div { class: "button-row",
+            match state {
+                BatchState::Running(control) => rsx! {
+                    button {
+                        onclick: move |_| {
+                            tracing::info!("Pausing batch upload");
+                            control.pause();
+                            batch_state.set(BatchState::Paused(control.clone()));
+                        },
+                        "{i18n.pause_upload()}"
+                    }
+                },
+                BatchState::Paused(control) => rsx! {
+                    button {
+                        onclick: move |_| {
+                            tracing::info!("Resuming batch upload");
+                            control.resume();
+                            batch_state.set(BatchState::Running(control.clone()));
+                        },
+                        "{i18n.resume_upload()}"
+                    }
+                },
+                BatchState::Idle | BatchState::Cancelling => rsx! {
+                    button { disabled: true, "{i18n.uploading()}" }
                 },
-                {if *is_uploading.read() { i18n.uploading() } else { i18n.start_upload() }}
+            }
+            button {
+                disabled: matches!(*batch_state.read(), BatchState::Cancelling),
+                onclick: move |_| {
+                    let state = batch_state.read().clone();
+                    if let BatchState::Running(control) | BatchState::Paused(control) = state {
+                        tracing::info!("Cancelling batch upload");
+                        control.cancel();
+                        batch_state.set(BatchState::Cancelling);
+                    }
+                },
+                "{i18n.cancel_upload()}"
             }
         }
     }
 }
 
+/// 开始上传的请求，由 App 中的协程依次处理
+pub struct UploadRequest {
+    user: String,
+    album_name: String,
+    force: bool,
+    control: BatchControl,
+}
+
+/// 上传批次的状态，由 App 提供，上传进行中切换页面不会中断进度更新
+#[derive(Clone, Copy, PartialEq)]
+pub struct UploadSession {
+    pub batch_state: Signal<BatchState>,
+    pub status: Signal<String>,
+    pub last_report: Signal<Option<UploadReport>>,
+    pub progress: Signal<BatchProgress>,
+}
+
+/// 在 App 中创建上传状态和处理上传请求的协程
+pub fn use_upload_session(
+    config: Signal<AppConfig>,
+    selected_files: Signal<Vec<PathBuf>>,
+) -> UploadSession {
+    let session = UploadSession {
+        batch_state: use_signal(BatchState::default),
+        status: use_signal(String::new),
+        last_report: use_signal(|| None),
+        progress: use_signal(BatchProgress::default),
+    };
+    use_coroutine(
+        move |mut requests: UnboundedReceiver<UploadRequest>| async move {
+            while let Some(request) = requests.next().await {
+                handle_upload(config, selected_files, session, request).await;
+            }
+        },
+    );
+    session
+}
+
+/// 请求开始上传，只能在 App 的子组件中调用
+pub fn start_upload(session: UploadSession, user: String, album_name: String, force: bool) {
+    let mut batch_state = session.batch_state;
+    if batch_state.peek().is_active() {
+        tracing::warn!("Upload already in progress, ignoring start request");
+        return;
+    }
+    // 同步标记为进行中，避免协程处理前重复点击启动第二个批次
+    let control = BatchControl::default();
+    batch_state.set(BatchState::Running(control.clone()));
+    consume_context::<Coroutine<UploadRequest>>().send(UploadRequest {
+        user,
+        album_name,
+        force,
+        control,
+    });
+}
+
 async fn handle_upload(
     config: Signal<AppConfig>,
-    selected_user: Signal<String>,
-    mut selected_files: Signal<Vec<FileHandle>>,
-    mut is_uploading: Signal<bool>,
-    mut status: Signal<String>,
+    mut selected_files: Signal<Vec<PathBuf>>,
+    session: UploadSession,
+    request: UploadRequest,
 ) {
-    is_uploading.set(true);
-    let file_list = selected_files.read().clone();
-    let total_files = file_list.len();
+    let UploadSession {
+        mut batch_state,
+        mut status,
+        mut last_report,
+        mut progress,
+    } = session;
+    let UploadRequest {
+        user: user_key,
+        album_name,
+        force,
+        control,
+    } = request;
+    last_report.set(None);
+    progress.set(BatchProgress::default());
+    let files = selected_files.read().clone();
     let i18n = get_i18n(&config);
 
-    let (server_url, api_key, concurrency) = {
+    let mut job = {
         let current_config = config.read();
-        let user_key = selected_user.read();
-        if let Some(user_info) = current_config.users.get(&*user_key) {
-            (
-                current_config.server_url.clone(),
-                user_info.api_key.clone(),
-                current_config.concurrency as usize,
-            )
-        } else {
+        let (Some(user_info), Some(server)) = (
+            current_config.users.get(&user_key),
+            current_config.server_for_user(&user_key),
+        ) else {
             tracing::warn!("API Key not found for user: {}", user_key);
             status.set(i18n.user_api_key_not_found().to_string());
-            is_uploading.set(false);
+            batch_state.set(BatchState::Idle);
             return;
+        };
+        let api_key = match user_info.require_api_key() {
+            Ok(api_key) => api_key,
+            Err(e) => {
+                tracing::error!("Cannot upload for user {}: {:#}", user_key, e);
+                status.set(i18n.api_key_unavailable(&format!("{:#}", e)));
+                batch_state.set(BatchState::Idle);
+                return;
+            }
+        };
+        let client = match ImmichClient::new(server, api_key) {
+            Ok(client) => client,
+            Err(e) => {
+                tracing::error!("Failed to create client for user {}: {:#}", user_key, e);
+                status.set(i18n.connection_failed(&format!("{:#}", e)));
+                batch_state.set(BatchState::Idle);
+                return;
+            }
+        };
+        UploadJob {
+            client,
+            user: user_key.clone(),
+            concurrency: current_config.concurrency as usize,
+            files,
+            continue_on_error: current_config.continue_on_error,
+            retry: current_config.retry.clone(),
+            album_id: None,
+            force,
+            control,
         }
     };
 
-    tracing::info!(
-        "Starting batch upload: {} files with concurrency {}",
-        total_files,
-        concurrency
+    let album_name = album_name.trim().to_string();
+    if !album_name.is_empty() {
+        status.set(i18n.preparing_album(&album_name));
+        match find_or_create_album(&job.client, &album_name).await {
+            Ok(album) => job.album_id = Some(album.id),
+            Err(e) => {
+                tracing::error!("Failed to prepare album {}: {}", album_name, e);
+                status.set(i18n.album_failed(&e.to_string()));
+                batch_state.set(BatchState::Idle);
+                return;
+            }
+        }
+    }
+
+    // 保存待上传队列，应用中途退出后可在下次启动时继续
+    let mut queue = UploadQueue::new(
+        &job.user,
+        Some(album_name.clone()).filter(|name| !name.is_empty()),
+        &job.files,
     );
-    status.set(i18n.start_parallel_upload(total_files));
-
-    let mut tasks = tokio::task::JoinSet::new();
-    let semaphore = std::sync::Arc::new(tokio::sync::Semaphore::new(concurrency));
-
-    for file_handle in file_list {
-        let permit = semaphore.clone().acquire_owned().await.unwrap_or_else(|e| {
-            tracing::error!("Failed to acquire semaphore: {}", e);
-            panic!("Failed to acquire semaphore: {}", e);
-        });
-        let server_url = server_url.clone();
-        let api_key = api_key.clone();
-        let file_name = file_handle.file_name();
-        let path = file_handle.path().to_path_buf();
-
-        tasks.spawn(async move {
-            let _permit = permit;
-            upload_asst(&server_url, &api_key, path)
-                .await
-                .map(|_| file_name.clone())
-                .map_err(|e| (file_name, e))
-        });
+    if let Err(e) = save_queue(&queue) {
+        tracing::warn!("Failed to save upload queue: {}", e);
     }
+    let mut last_saved = Instant::now();
 
-    let mut finished = 0;
-    let mut has_error = false;
-
-    while let Some(result) = tasks.join_next().await {
-        match result.unwrap_or_else(|e| {
-            tracing::error!("Task execution failed: {}", e);
-            Err((
-                "unknown file".to_string(),
-                anyhow::anyhow!("Task execution failed: {}", e),
-            ))
-        }) {
-            Ok(file_name) => {
-                finished += 1;
-                tracing::debug!(
-                    "File uploaded successfully ({}/{}): {}",
-                    finished,
-                    total_files,
-                    file_name
-                );
-                status.set(i18n.upload_success(finished, total_files, &file_name));
+    let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+    let upload = tokio::spawn(run_upload(job, tx));
+
+    while let Some(event) = rx.recv().await {
+        progress.write().apply(&event);
+        // 未及时保存的已完成文件在继续上传时会被本地上传记录跳过
+        if queue.apply(&event) && last_saved.elapsed() >= QUEUE_SAVE_INTERVAL {
+            if let Err(e) = save_queue(&queue) {
+                tracing::warn!("Failed to save upload queue: {}", e);
+            }
+            last_saved = Instant::now();
+        }
+        match event {
+            UploadEvent::CheckingDuplicates { total } => {
+                status.set(i18n.checking_duplicates(total));
             }
-            Err((file_name, e)) => {
-                tracing::error!("File upload failed: {} - {}", file_name, e);
-                status.set(i18n.upload_failed(&file_name, &e.to_string()));
-                tasks.abort_all();
-                has_error = true;
-                break;
+            UploadEvent::Started { total, .. } => {
+                status.set(i18n.start_parallel_upload(total));
+            }
+            UploadEvent::Skipped { .. }
+            | UploadEvent::Progress { .. }
+            | UploadEvent::Cancelled { .. } => {}
+            UploadEvent::Uploaded {
+                file_name,
+                finished,
+                total,
+                ..
+            } => {
+                status.set(i18n.upload_success(finished, total, &file_name));
+            }
+            UploadEvent::Failed {
+                file_name, error, ..
+            } => {
+                status.set(i18n.upload_failed(&file_name, &i18n.upload_error(&error)));
             }
         }
     }
 
-    if !has_error {
-        tracing::info!("All files uploaded successfully: {} files", total_files);
-        status.set(i18n.all_files_uploaded(total_files));
-        selected_files.set(Vec::new());
+    match upload.await {
+        Ok(report) => {
+            if report.cancelled {
+                status.set(i18n.upload_cancelled(report.uploaded.len(), report.aborted.len()));
+            } else if !report.failed.is_empty() {
+                status.set(
+                    i18n.upload_finished_with_failures(report.uploaded.len(), report.failed.len()),
+                );
+            } else if report.is_success() && report.skipped > 0 {
+                status
+                    .set(i18n.upload_finished_with_skipped(report.uploaded.len(), report.skipped));
+            } else if report.is_success() {
+                status.set(i18n.all_files_uploaded(report.uploaded.len()));
+            }
+            // 取消后保留未完成的文件，下次启动时可以继续上传
+            let result = if report.cancelled && !report.aborted.is_empty() {
+                save_queue(&queue)
+            } else {
+                clear_queue()
+            };
+            if let Err(e) = result {
+                tracing::warn!("Failed to update upload queue: {}", e);
+            }
+            selected_files.set(Vec::new());
+            last_report.set(Some(report));
+        }
+        Err(e) => tracing::error!("Upload task failed: {}", e),
     }
 
-    is_uploading.set(false);
+    batch_state.set(BatchState::Idle);
+}
+
+#[component]
+pub fn UploadOptions(
+    config: Signal<AppConfig>,
+    batch_state: Signal<BatchState>,
+    mut force_upload: Signal<bool>,
+) -> Element {
+    let i18n = get_i18n(&config);
+
+    rsx! {
+        div { class: "upload-options",
+            label {
+                input {
+                    r#type: "checkbox",
+                    disabled: batch_state.read().is_active(),
+                    checked: config.read().continue_on_error,
+                    onchange: move |evt| {
+                        let mut temp_config = config.read().clone();
+                        temp_config.continue_on_error = evt.checked();
+
+                        if let Err(e) = save_config(&mut temp_config) {
+                            tracing::error!("Failed to save continue-on-error option: {}", e);
+                        } else {
+                            tracing::info!("Continue on error updated: {}", temp_config.continue_on_error);
+                            config.set(temp_config);
+                        }
+                    },
+                }
+                "{i18n.continue_on_error()}"
+            }
+            label { title: "{i18n.force_upload_hint()}",
+                input {
+                    r#type: "checkbox",
+                    disabled: batch_state.read().is_active(),
+                    checked: *force_upload.read(),
+                    onchange: move |evt| force_upload.set(evt.checked()),
+                }
+                "{i18n.force_upload()}"
+            }
+        }
+    }
 }
@@ -1,4 +1,6 @@
-use crate::components::{Home, Settings};
+use crate::components::{
+    History, Home, SelectedFiles, Settings, use_folder_watcher, use_upload_session,
+};
 use crate::core::load_config;
 use dioxus::prelude::*;
 #[derive(Routable, Clone, PartialEq, Debug)]
@@ -7,13 +9,21 @@ pub enum AppRoute {
     Home {},
     #[route("/settings")]
     Settings {},
+    #[route("/history")]
+    History {},
 }
 
 #[component]
 pub fn App() -> Element {
     let config = load_config().unwrap_or_default();
 
-    use_context_provider(|| Signal::new(config));
+    let config = use_context_provider(|| Signal::new(config));
+    let watcher_status = use_folder_watcher(config);
+    use_context_provider(|| watcher_status);
+    let SelectedFiles(selected_files) =
+        use_context_provider(|| SelectedFiles(Signal::new(Vec::new())));
+    let upload_session = use_upload_session(config, selected_files);
+    use_context_provider(|| upload_session);
 
     rsx! {
         Router::<AppRoute> {}
@@ -1,14 +1,20 @@
+mod bandwidth_config;
 mod concurrency_config;
 mod icons;
 mod language_config;
+mod retry_config;
 mod server_config;
 mod user_management;
+mod watch_folders;
 
+use bandwidth_config::*;
 use concurrency_config::*;
 use icons::*;
 use language_config::*;
+use retry_config::*;
 use server_config::*;
 use user_management::*;
+use watch_folders::*;
 
 use crate::components::{AppRoute, get_i18n};
 use crate::core::AppConfig;
@@ -37,8 +43,11 @@ pub fn Settings() -> Element {
 
         ServerConfig { config, status }
         ConcurrencyConfig { config, status }
+        RetryPolicyConfig { config, status }
+        BandwidthLimitConfig { config, status }
         LanguageConfig { config, status }
         UserManagement { config, status }
+        WatchFolderConfig { config, status }
 
         div { class: "status-text", "{status}" }
     }
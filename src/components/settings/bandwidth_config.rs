@@ -0,0 +1,115 @@
+use crate::components::get_i18n;
+use crate::core::{AppConfig, BandwidthConfig, TimeWindow, save_config, set_bandwidth};
+use chrono::NaiveTime;
+use dioxus::prelude::*;
+
+/// 时间输入框使用的格式
+const TIME_FORMAT: &str = "%H:%M";
+
+#[component]
+pub fn BandwidthLimitConfig(config: Signal<AppConfig>, mut status: Signal<String>) -> Element {
+    let i18n = get_i18n(&config);
+    let window = config.read().bandwidth.unlimited_window;
+    let mut limit_kbps = use_signal(|| config.read().bandwidth.limit_kbps);
+    let mut schedule_enabled = use_signal(|| window.is_some());
+    let mut window_start = use_signal(|| {
+        window
+            .map(|window| window.start)
+            .unwrap_or_default()
+            .format(TIME_FORMAT)
+            .to_string()
+    });
+    let mut window_end = use_signal(|| {
+        // 默认 00:00 - 07:00
+        window
+            .map(|window| window.end)
+            .or(NaiveTime::from_hms_opt(7, 0, 0))
+            .unwrap_or_default()
+            .format(TIME_FORMAT)
+            .to_string()
+    });
+
+    rsx! {
+        div { class: "bandwidth-group",
+            label { "{i18n.bandwidth_limit()}" }
+            div { class: "bandwidth-input-group",
+                input {
+                    r#type: "number",
+                    min: "0",
+                    step: "100",
+                    value: "{limit_kbps}",
+                    oninput: move |evt| {
+                        if let Ok(val) = evt.value().parse::<u32>() {
+                            limit_kbps.set(val);
+                        }
+                    },
+                }
+
+                button {
+                    onclick: move |_| {
+                        let i18n = get_i18n(&config);
+                        let parse = |value: &str| NaiveTime::parse_from_str(value, TIME_FORMAT).ok();
+                        let unlimited_window = if *schedule_enabled.read() {
+                            match (parse(&window_start.read()), parse(&window_end.read())) {
+                                (Some(start), Some(end)) if start != end => {
+                                    Some(TimeWindow { start, end })
+                                }
+                                _ => {
+                                    tracing::warn!(
+                                        "Invalid unlimited window: {} - {}",
+                                        window_start.read(),
+                                        window_end.read()
+                                    );
+                                    status.set(i18n.invalid_bandwidth_config().to_string());
+                                    return;
+                                }
+                            }
+                        } else {
+                            None
+                        };
+
+                        let mut temp_config = config.read().clone();
+                        temp_config.bandwidth = BandwidthConfig {
+                            limit_kbps: *limit_kbps.read(),
+                            unlimited_window,
+                        };
+
+                        if let Err(e) = save_config(&mut temp_config) {
+                            tracing::error!("Failed to save bandwidth config: {}", e);
+                            status.set(i18n.save_failed(&e.to_string()));
+                        } else {
+                            // 立即应用到正在进行的上传
+                            set_bandwidth(&temp_config.bandwidth);
+                            config.set(temp_config);
+                            status.set(i18n.bandwidth_config_saved().to_string());
+                        }
+                    },
+                    "{i18n.save()}"
+                }
+            }
+            div { class: "bandwidth-schedule",
+                label {
+                    input {
+                        r#type: "checkbox",
+                        checked: *schedule_enabled.read(),
+                        onchange: move |evt| schedule_enabled.set(evt.checked()),
+                    }
+                    "{i18n.unlimited_window()}"
+                }
+                input {
+                    r#type: "time",
+                    disabled: !*schedule_enabled.read(),
+                    value: "{window_start}",
+                    oninput: move |evt| window_start.set(evt.value()),
+                }
+                span { "-" }
+                input {
+                    r#type: "time",
+                    disabled: !*schedule_enabled.read(),
+                    value: "{window_end}",
+                    oninput: move |evt| window_end.set(evt.value()),
+                }
+            }
+        }
+    }
+}
@@ -32,7 +32,7 @@ pub fn ConcurrencyConfig(config: Signal<AppConfig>, mut status: Signal<String>)
                         if temp_config.concurrency < 1 || temp_config.concurrency > 16 {
                             tracing::warn!("Invalid concurrency setting: {}", temp_config.concurrency);
                             status.set(i18n.invalid_concurrency().to_string());
-                        } else if let Err(e) = save_config(&temp_config) {
+                        } else if let Err(e) = save_config(&mut temp_config) {
                             tracing::error!("Failed to save concurrency config: {}", e);
                             status.set(i18n.save_failed(&e.to_string()));
                         } else {
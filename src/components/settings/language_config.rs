@@ -29,7 +29,7 @@ pub fn LanguageConfig(config: Signal<AppConfig>, mut status: Signal<String>) ->
                         temp_config.language = selected_language.read().clone();
                         let i18n = get_i18n(&config);
 
-                        if let Err(e) = save_config(&temp_config) {
+                        if let Err(e) = save_config(&mut temp_config) {
                             tracing::error!("Failed to save language config: {}", e);
                             status.set(i18n.save_failed(&e.to_string()));
                         } else {
@@ -0,0 +1,88 @@
+use crate::components::get_i18n;
+use crate::core::{AppConfig, RetryConfig, save_config};
+use dioxus::prelude::*;
+
+#[component]
+pub fn RetryPolicyConfig(config: Signal<AppConfig>, mut status: Signal<String>) -> Element {
+    let i18n = get_i18n(&config);
+    let mut max_attempts = use_signal(|| config.read().retry.max_attempts);
+    let mut base_delay_ms = use_signal(|| config.read().retry.base_delay_ms);
+    let mut jitter_ms = use_signal(|| config.read().retry.jitter_ms);
+
+    rsx! {
+        div { class: "retry-group",
+            label { "{i18n.retry_policy()}" }
+            div { class: "retry-input-group",
+                input {
+                    r#type: "number",
+                    min: "1",
+                    max: "10",
+                    title: "{i18n.max_attempts()}",
+                    placeholder: "{i18n.max_attempts()}",
+                    value: "{max_attempts}",
+                    oninput: move |evt| {
+                        if let Ok(val) = evt.value().parse::<u32>() {
+                            max_attempts.set(val);
+                        }
+                    },
+                }
+                input {
+                    r#type: "number",
+                    min: "100",
+                    max: "60000",
+                    step: "100",
+                    title: "{i18n.base_delay_ms()}",
+                    placeholder: "{i18n.base_delay_ms()}",
+                    value: "{base_delay_ms}",
+                    oninput: move |evt| {
+                        if let Ok(val) = evt.value().parse::<u64>() {
+                            base_delay_ms.set(val);
+                        }
+                    },
+                }
+                input {
+                    r#type: "number",
+                    min: "0",
+                    max: "60000",
+                    step: "100",
+                    title: "{i18n.jitter_ms()}",
+                    placeholder: "{i18n.jitter_ms()}",
+                    value: "{jitter_ms}",
+                    oninput: move |evt| {
+                        if let Ok(val) = evt.value().parse::<u64>() {
+                            jitter_ms.set(val);
+                        }
+                    },
+                }
+
+                button {
+                    onclick: move |_| {
+                        let mut temp_config = config.read().clone();
+                        temp_config.retry = RetryConfig {
+                            max_attempts: *max_attempts.read(),
+                            base_delay_ms: *base_delay_ms.read(),
+                            jitter_ms: *jitter_ms.read(),
+                        };
+                        let i18n = get_i18n(&config);
+
+                        if !(1..=10).contains(&temp_config.retry.max_attempts)
+                            || !(100..=60000).contains(&temp_config.retry.base_delay_ms)
+                            || temp_config.retry.jitter_ms > 60000
+                        {
+                            tracing::warn!("Invalid retry setting: {:?}", temp_config.retry);
+                            status.set(i18n.invalid_retry_config().to_string());
+                        } else if let Err(e) = save_config(&mut temp_config) {
+                            tracing::error!("Failed to save retry config: {}", e);
+                            status.set(i18n.save_failed(&e.to_string()));
+                        } else {
+                            tracing::info!("Retry policy updated: {:?}", temp_config.retry);
+                            config.set(temp_config);
+                            status.set(i18n.retry_config_saved().to_string());
+                        }
+                    },
+                    "{i18n.save()}"
+                }
+            }
+        }
+    }
+}
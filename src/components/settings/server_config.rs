@@ -1,40 +1,271 @@
-use crate::components::get_i18n;
-use crate::core::{AppConfig, save_config};
+use super::icons::DeleteIcon;
+use crate::components::{describe_connection, get_i18n};
+use crate::core::{
+    AppConfig, I18n, ImmichClient, ServerProfile, save_config, test_connection, validate_server_url,
+};
 use dioxus::prelude::*;
+use rfd::AsyncFileDialog;
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+/// 解析每行一个的 `名称: 值` 请求头，出错时返回无法解析的行
+fn parse_headers(input: &str) -> Result<BTreeMap<String, String>, String> {
+    let mut headers = BTreeMap::new();
+    for line in input.lines().map(str::trim).filter(|line| !line.is_empty()) {
+        match line.split_once(':') {
+            Some((name, value)) if !name.trim().is_empty() => {
+                headers.insert(name.trim().to_string(), value.trim().to_string());
+            }
+            _ => return Err(line.to_string()),
+        }
+    }
+    Ok(headers)
+}
+
+/// 根据表单内容生成服务器配置，地址或请求头无效时返回提示信息
+fn profile_from_form(
+    i18n: &I18n,
+    url: &str,
+    headers: &str,
+    accept_invalid_certs: bool,
+    ca_certificate: Option<PathBuf>,
+) -> Result<ServerProfile, String> {
+    let url = url.trim();
+    if let Err(e) = validate_server_url(url) {
+        return Err(i18n.invalid_server_url(&e.to_string()));
+    }
+    let headers = parse_headers(headers).map_err(|line| i18n.invalid_header(&line))?;
+    Ok(ServerProfile {
+        url: url.trim_end_matches('/').to_string(),
+        headers,
+        accept_invalid_certs,
+        ca_certificate,
+    })
+}
+
+fn format_headers(headers: &BTreeMap<String, String>) -> String {
+    headers
+        .iter()
+        .map(|(name, value)| format!("{}: {}", name, value))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
 
 #[component]
 pub fn ServerConfig(config: Signal<AppConfig>, mut status: Signal<String>) -> Element {
     let i18n = get_i18n(&config);
-    let mut server_url = use_signal(|| config.read().server_url.clone());
+    let mut profile_name = use_signal(String::new);
+    let mut server_url = use_signal(String::new);
+    let mut headers = use_signal(String::new);
+    let mut accept_invalid_certs = use_signal(|| false);
+    let mut ca_certificate = use_signal(|| None::<PathBuf>);
+    let mut testing = use_signal(|| false);
+
+    // 点击配置卡片时载入表单进行编辑
+    let edit_profile = move |name: String| {
+        let Some(profile) = config.read().profiles.get(&name).cloned() else {
+            return;
+        };
+        profile_name.set(name);
+        server_url.set(profile.url);
+        headers.set(format_headers(&profile.headers));
+        accept_invalid_certs.set(profile.accept_invalid_certs);
+        ca_certificate.set(profile.ca_certificate);
+    };
 
     rsx! {
-        div { class: "server-group",
-            label { "{i18n.server_url()}" }
-            div { class: "server-input-group",
+        div { class: "watch-group profile-group",
+            label { "{i18n.server_profiles()}" }
+            div { class: "watch-row",
                 input {
+                    placeholder: "{i18n.profile_name()}",
+                    value: "{profile_name}",
+                    oninput: move |evt| profile_name.set(evt.value()),
+                }
+                input {
+                    placeholder: "http://your-immich-server:2283",
                     value: "{server_url}",
                     oninput: move |evt| server_url.set(evt.value()),
-                    placeholder: "http://your-immich-server:2283",
                 }
+            }
+            div { class: "watch-row",
+                textarea {
+                    class: "profile-headers",
+                    placeholder: "{i18n.custom_headers()}",
+                    value: "{headers}",
+                    oninput: move |evt| headers.set(evt.value()),
+                }
+            }
+            div { class: "watch-row",
+                button {
+                    onclick: move |_| async move {
+                        let i18n = get_i18n(&config);
+                        if let Some(file) = AsyncFileDialog::new()
+                            .add_filter("PEM", &["pem", "crt", "cer"])
+                            .set_title(i18n.ca_certificate())
+                            .pick_file()
+                            .await
+                        {
+                            ca_certificate.set(Some(file.path().to_path_buf()));
+                        }
+                    },
+                    "{i18n.ca_certificate()}"
+                }
+                span { class: "watch-path",
+                    {ca_certificate.read().as_ref().map(|path| path.display().to_string()).unwrap_or_default()}
+                }
+                if ca_certificate.read().is_some() {
+                    button {
+                        onclick: move |_| ca_certificate.set(None),
+                        "{i18n.clear()}"
+                    }
+                }
+                label { class: "watch-recursive",
+                    input {
+                        r#type: "checkbox",
+                        checked: *accept_invalid_certs.read(),
+                        onchange: move |evt| accept_invalid_certs.set(evt.checked()),
+                    }
+                    "{i18n.accept_invalid_certs()}"
+                }
+                button {
+                    disabled: *testing.read(),
+                    onclick: move |_| async move {
+                        let i18n = get_i18n(&config);
+                        let profile = match profile_from_form(
+                            &i18n,
+                            &server_url.read(),
+                            &headers.read(),
+                            *accept_invalid_certs.read(),
+                            ca_certificate.read().clone(),
+                        ) {
+                            Ok(profile) => profile,
+                            Err(message) => {
+                                status.set(message);
+                                return;
+                            }
+                        };
 
+                        testing.set(true);
+                        status.set(i18n.testing_connection().to_string());
+                        let result = match ImmichClient::anonymous(&profile) {
+                            Ok(client) => test_connection(&client).await,
+                            Err(e) => Err(e),
+                        };
+                        match result {
+                            Ok(info) => status.set(describe_connection(&i18n, &info)),
+                            Err(e) => {
+                                tracing::warn!("Connection test failed: {:#}", e);
+                                status.set(i18n.connection_failed(&format!("{:#}", e)));
+                            }
+                        }
+                        testing.set(false);
+                    },
+                    "{i18n.test_connection()}"
+                }
                 button {
                     onclick: move |_| {
-                        let mut temp_config = config.read().clone();
-                        temp_config.server_url = server_url.read().clone();
                         let i18n = get_i18n(&config);
+                        let name = profile_name.read().trim().to_string();
+                        if name.is_empty() || server_url.read().trim().is_empty() {
+                            status.set(i18n.please_fill_complete_info().to_string());
+                            return;
+                        }
+                        let profile = match profile_from_form(
+                            &i18n,
+                            &server_url.read(),
+                            &headers.read(),
+                            *accept_invalid_certs.read(),
+                            ca_certificate.read().clone(),
+                        ) {
+                            Ok(profile) => profile,
+                            Err(message) => {
+                                status.set(message);
+                                return;
+                            }
+                        };
 
-                        if let Err(e) = save_config(&temp_config) {
-                            tracing::error!("Failed to save server URL config: {}", e);
+                        let mut temp_config = config.read().clone();
+                        temp_config.profiles.insert(name.clone(), profile);
+                        if !temp_config.profiles.contains_key(&temp_config.current_profile) {
+                            temp_config.current_profile = name.clone();
+                        }
+
+                        if let Err(e) = save_config(&mut temp_config) {
+                            tracing::error!("Failed to save server profile: {}", e);
                             status.set(i18n.save_failed(&e.to_string()));
                         } else {
-                            tracing::info!("Server URL updated: {}", temp_config.server_url);
+                            tracing::info!("Server profile saved: {}", name);
                             config.set(temp_config);
-                            status.set(i18n.server_url_saved().to_string());
+                            status.set(i18n.profile_saved(&name));
                         }
                     },
                     "{i18n.save()}"
                 }
             }
+
+            div { class: "watch-list",
+                for (name, profile) in config.read().profiles.clone() {
+                    ProfileCard { name, profile, config, status, on_edit: edit_profile }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn ProfileCard(
+    name: String,
+    profile: ServerProfile,
+    config: Signal<AppConfig>,
+    mut status: Signal<String>,
+    on_edit: EventHandler<String>,
+) -> Element {
+    let i18n = get_i18n(&config);
+    let user_count = config.read().profile_users(&name).len();
+
+    rsx! {
+        div { class: "watch-card profile-card",
+            div {
+                class: "watch-info",
+                onclick: {
+                    let name = name.clone();
+                    move |_| on_edit.call(name.clone())
+                },
+                span { class: "watch-path", "{name}" }
+                span { class: "watch-details", "{profile.url} · {i18n.profile_user_count(user_count)}" }
+            }
+            button {
+                class: "action-btn delete-btn",
+                title: "{i18n.delete()}",
+                onclick: {
+                    let name = name.clone();
+                    move |_| {
+                        let i18n = get_i18n(&config);
+                        if user_count > 0 {
+                            status.set(i18n.profile_in_use(&name));
+                            return;
+                        }
+                        let mut temp_config = config.read().clone();
+                        temp_config.profiles.remove(&name);
+                        if temp_config.current_profile == name {
+                            temp_config.current_profile =
+                                temp_config.profiles.keys().next().cloned().unwrap_or_default();
+                        }
+
+                        if let Err(e) = save_config(&mut temp_config) {
+                            tracing::error!("Failed to delete server profile: {}", e);
+                            status.set(i18n.save_failed(&e.to_string()));
+                        } else {
+                            tracing::info!("Server profile deleted: {}", name);
+                            config.set(temp_config);
+                            status.set(i18n.profile_deleted(&name));
+                        }
+                    }
+                },
+                DeleteIcon {}
+                span { "{i18n.delete()}" }
+            }
         }
     }
 }
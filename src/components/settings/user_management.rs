@@ -1,6 +1,9 @@
 use super::icons::{DeleteIcon, LockIcon, StarIcon};
-use crate::components::get_i18n;
-use crate::core::{AppConfig, UserConfig, save_config};
+use crate::components::{describe_connection, get_i18n};
+use crate::core::{
+    AppConfig, ImmichClient, PASSPHRASE_ENV, UserConfig, delete_api_key, save_config, secret_store,
+    test_connection,
+};
 use dioxus::prelude::*;
 
 #[component]
@@ -8,12 +11,32 @@ pub fn UserManagement(config: Signal<AppConfig>, mut status: Signal<String>) ->
     let i18n = get_i18n(&config);
     let mut new_username = use_signal(|| String::new());
     let mut new_api_key = use_signal(|| String::new());
+    let mut new_profile = use_signal(|| config.read().current_profile.clone());
+    let mut testing = use_signal(|| false);
+    let profiles: Vec<_> = config.read().profiles.keys().cloned().collect();
 
     rsx! {
         div { class: "user-group",
             label { "{i18n.user_management()}" }
+            // 口令只能通过环境变量设置，需要提示用户如何启用加密文件
+            if secret_store().is_none() {
+                div { class: "secret-store-hint", "{i18n.no_secret_store(PASSPHRASE_ENV)}" }
+            }
             div { class: "user-input-group",
                 div { class: "adduser-row",
+                    select {
+                        class: "profile-select",
+                        value: new_profile.read().clone(),
+                        onchange: move |evt| new_profile.set(evt.value()),
+                        for profile in profiles {
+                            option {
+                                value: "{profile}",
+                                selected: *new_profile.read() == *profile,
+                                "{profile}"
+                            }
+                        }
+                    }
+
                     input {
                         class: "user-input",
                         placeholder: "{i18n.username()}",
@@ -28,24 +51,61 @@ pub fn UserManagement(config: Signal<AppConfig>, mut status: Signal<String>) ->
                         oninput: move |evt| new_api_key.set(evt.value()),
                     }
 
+                    button {
+                        disabled: *testing.read(),
+                        onclick: move |_| async move {
+                            let i18n = get_i18n(&config);
+                            let key = new_api_key.read().trim().to_string();
+                            let server = config.read().profiles.get(&*new_profile.read()).cloned();
+                            let Some(server) = server.filter(|_| !key.is_empty()) else {
+                                status.set(i18n.please_fill_complete_info().to_string());
+                                return;
+                            };
+
+                            testing.set(true);
+                            status.set(i18n.testing_connection().to_string());
+                            let result = match ImmichClient::new(&server, &key) {
+                                Ok(client) => test_connection(&client).await,
+                                Err(e) => Err(e),
+                            };
+                            match result {
+                                Ok(info) => status.set(describe_connection(&i18n, &info)),
+                                Err(e) => {
+                                    tracing::warn!("Connection test failed: {:#}", e);
+                                    status.set(i18n.connection_failed(&format!("{:#}", e)));
+                                }
+                            }
+                            testing.set(false);
+                        },
+                        "{i18n.test()}"
+                    }
+
                     button {
                         onclick: move |_| {
                             let name = new_username.read().trim().to_string();
                             let key = new_api_key.read().trim().to_string();
+                            let profile = new_profile.read().clone();
                             let i18n = get_i18n(&config);
                             let mut temp_config = config.read().clone();
 
-                            if name.is_empty() || key.is_empty() {
+                            if name.is_empty() || key.is_empty() || !temp_config.profiles.contains_key(&profile) {
                                 status.set(i18n.please_fill_complete_info().to_string());
                                 return;
                             }
 
-                            temp_config.users.insert(name.clone(), UserConfig { api_key: key.clone() });
+                            // 密钥存储的账户只有用户名，同名用户会覆盖其他服务器配置下的 API Key
+                            if let Some(existing) = temp_config.users.get(&name) {
+                                status.set(i18n.user_exists(&name, &existing.profile));
+                                return;
+                            }
+
+                            temp_config.users.insert(name.clone(), UserConfig::new(profile, key.clone()));
                             if temp_config.current_user.is_empty() {
                                 temp_config.current_user = name.clone();
+                                temp_config.current_profile = temp_config.users[&name].profile.clone();
                             }
 
-                            if let Err(e) = save_config(&temp_config) {
+                            if let Err(e) = save_config(&mut temp_config) {
                                 tracing::error!("Failed to save user config: {}", e);
                                 status.set(i18n.save_failed(&e.to_string()));
                             } else {
@@ -88,12 +148,19 @@ fn UserList(config: Signal<AppConfig>, mut status: Signal<String>) -> Element {
 fn UserCard(username: String, config: Signal<AppConfig>, mut status: Signal<String>) -> Element {
     let i18n = get_i18n(&config);
     let is_current = username == config.read().current_user;
+    let profile = config
+        .read()
+        .users
+        .get(&username)
+        .map(|user| user.profile.clone())
+        .unwrap_or_default();
 
     rsx! {
         div { class: "user-card",
             div { class: "user-name",
                 if is_current { StarIcon {} }
                 "{username}"
+                span { class: "user-profile", "{profile}" }
             }
             div { class: "user-actions",
                 button {
@@ -105,8 +172,11 @@ fn UserCard(username: String, config: Signal<AppConfig>, mut status: Signal<Stri
                             let i18n = get_i18n(&config);
                             let mut temp_config = config.read().clone();
                             temp_config.current_user = temp_username.clone();
+                            if let Some(user) = temp_config.users.get(&temp_username) {
+                                temp_config.current_profile = user.profile.clone();
+                            }
 
-                            if let Err(e) = save_config(&temp_config) {
+                            if let Err(e) = save_config(&mut temp_config) {
                                 tracing::error!("Failed to set default user: {}", e);
                                 status.set(i18n.save_failed(&e.to_string()));
                             } else {
@@ -128,22 +198,27 @@ fn UserCard(username: String, config: Signal<AppConfig>, mut status: Signal<Stri
                         move |_| {
                             let i18n = get_i18n(&config);
                             let mut temp_config = config.read().clone();
-                            temp_config.users.remove(&temp_username);
+                            let removed = temp_config.users.remove(&temp_username);
 
+                            // 只从当前服务器配置中选择新的当前用户，保持两者一致
                             if temp_config.current_user == temp_username {
                                 temp_config.current_user = temp_config
-                                    .users
-                                    .keys()
+                                    .profile_users(&temp_config.current_profile)
+                                    .into_iter()
                                     .next()
-                                    .cloned()
                                     .unwrap_or_default();
                             }
 
-                            if let Err(e) = save_config(&temp_config) {
+                            if let Err(e) = save_config(&mut temp_config) {
                                 tracing::error!("Failed to delete user: {}", e);
                                 status.set(i18n.save_failed(&e.to_string()));
                             } else {
                                 tracing::info!("User deleted: {}", temp_username);
+                                if let Some(user) = &removed
+                                    && let Err(e) = delete_api_key(user)
+                                {
+                                    tracing::warn!("Failed to delete API Key from secret store: {}", e);
+                                }
                                 config.set(temp_config);
                                 status.set(i18n.user_deleted(&temp_username));
                             }
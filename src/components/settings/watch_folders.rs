@@ -0,0 +1,191 @@
+use super::icons::DeleteIcon;
+use crate::components::get_i18n;
+use crate::core::{AppConfig, WatchedFolder, save_config, validate_watched_folder};
+use dioxus::prelude::*;
+use rfd::AsyncFileDialog;
+use std::path::PathBuf;
+
+/// 将逗号分隔的 glob 列表拆分为数组
+fn split_patterns(input: &str) -> Vec<String> {
+    input
+        .split(',')
+        .map(|pattern| pattern.trim().to_string())
+        .filter(|pattern| !pattern.is_empty())
+        .collect()
+}
+
+#[component]
+pub fn WatchFolderConfig(config: Signal<AppConfig>, mut status: Signal<String>) -> Element {
+    let i18n = get_i18n(&config);
+    let mut folder_path = use_signal(|| None::<PathBuf>);
+    let mut folder_user = use_signal(|| config.read().current_user.clone());
+    let mut recursive = use_signal(|| true);
+    let mut include = use_signal(String::new);
+    let mut exclude = use_signal(String::new);
+
+    let mut users: Vec<_> = config.read().users.keys().cloned().collect();
+    users.sort();
+
+    rsx! {
+        div { class: "watch-group",
+            label { "{i18n.watched_folders()}" }
+            div { class: "watch-input-group",
+                div { class: "watch-row",
+                    button {
+                        onclick: move |_| async move {
+                            let i18n = get_i18n(&config);
+                            if let Some(folder) = AsyncFileDialog::new()
+                                .set_title(i18n.choose_folder())
+                                .pick_folder()
+                                .await
+                            {
+                                folder_path.set(Some(folder.path().to_path_buf()));
+                            }
+                        },
+                        "{i18n.choose_folder()}"
+                    }
+                    span { class: "watch-path",
+                        {folder_path.read().as_ref().map(|path| path.display().to_string()).unwrap_or_default()}
+                    }
+                    select {
+                        value: folder_user.read().clone(),
+                        onchange: move |evt| folder_user.set(evt.value()),
+                        for username in users {
+                            option {
+                                value: "{username}",
+                                selected: *folder_user.read() == *username,
+                                "{username}"
+                            }
+                        }
+                    }
+                }
+                div { class: "watch-row",
+                    input {
+                        placeholder: "{i18n.include_patterns()}",
+                        value: "{include}",
+                        oninput: move |evt| include.set(evt.value()),
+                    }
+                    input {
+                        placeholder: "{i18n.exclude_patterns()}",
+                        value: "{exclude}",
+                        oninput: move |evt| exclude.set(evt.value()),
+                    }
+                    label { class: "watch-recursive",
+                        input {
+                            r#type: "checkbox",
+                            checked: *recursive.read(),
+                            onchange: move |evt| recursive.set(evt.checked()),
+                        }
+                        "{i18n.recursive()}"
+                    }
+                    button {
+                        onclick: move |_| {
+                            let i18n = get_i18n(&config);
+                            let user = folder_user.read().clone();
+                            let Some(path) = folder_path.read().clone() else {
+                                status.set(i18n.please_fill_complete_info().to_string());
+                                return;
+                            };
+                            if user.is_empty() {
+                                status.set(i18n.please_fill_complete_info().to_string());
+                                return;
+                            }
+
+                            let folder = WatchedFolder {
+                                path,
+                                user,
+                                recursive: *recursive.read(),
+                                include: split_patterns(&include.read()),
+                                exclude: split_patterns(&exclude.read()),
+                            };
+                            if let Err(e) = validate_watched_folder(&folder) {
+                                tracing::warn!("Invalid watched folder: {}", e);
+                                status.set(i18n.invalid_watched_folder(&e.to_string()));
+                                return;
+                            }
+
+                            let mut temp_config = config.read().clone();
+                            temp_config.watched_folders.retain(|existing| existing.path != folder.path);
+                            temp_config.watched_folders.push(folder.clone());
+
+                            if let Err(e) = save_config(&mut temp_config) {
+                                tracing::error!("Failed to save watched folder: {}", e);
+                                status.set(i18n.save_failed(&e.to_string()));
+                            } else {
+                                tracing::info!("Watched folder added: {:?}", folder.path);
+                                config.set(temp_config);
+                                folder_path.set(None);
+                                include.set(String::new());
+                                exclude.set(String::new());
+                                status.set(i18n.watched_folder_added().to_string());
+                            }
+                        },
+                        "{i18n.add()}"
+                    }
+                }
+            }
+
+            div { class: "watch-list",
+                if config.read().watched_folders.is_empty() {
+                    div { "{i18n.no_watched_folders()}" }
+                } else {
+                    for folder in config.read().watched_folders.clone() {
+                        WatchFolderCard { folder, config, status }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn WatchFolderCard(
+    folder: WatchedFolder,
+    config: Signal<AppConfig>,
+    mut status: Signal<String>,
+) -> Element {
+    let i18n = get_i18n(&config);
+    let mut details = vec![folder.user.clone()];
+    if folder.recursive {
+        details.push(i18n.recursive().to_string());
+    }
+    if !folder.include.is_empty() {
+        details.push(format!("+ {}", folder.include.join(", ")));
+    }
+    if !folder.exclude.is_empty() {
+        details.push(format!("- {}", folder.exclude.join(", ")));
+    }
+    let details = details.join(" · ");
+
+    rsx! {
+        div { class: "watch-card",
+            div { class: "watch-info",
+                span { class: "watch-path", "{folder.path.display()}" }
+                span { class: "watch-details", "{details}" }
+            }
+            button {
+                class: "action-btn delete-btn",
+                title: "{i18n.delete()}",
+                onclick: {
+                    let path = folder.path.clone();
+                    move |_| {
+                        let i18n = get_i18n(&config);
+                        let mut temp_config = config.read().clone();
+                        temp_config.watched_folders.retain(|existing| existing.path != path);
+
+                        if let Err(e) = save_config(&mut temp_config) {
+                            tracing::error!("Failed to remove watched folder: {}", e);
+                            status.set(i18n.save_failed(&e.to_string()));
+                        } else {
+                            tracing::info!("Watched folder removed: {:?}", path);
+                            config.set(temp_config);
+                            status.set(i18n.watched_folder_removed().to_string());
+                        }
+                    }
+                },
+                DeleteIcon {}
+                span { "{i18n.delete()}" }
+            }
+        }
+    }
+}
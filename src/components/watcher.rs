@@ -0,0 +1,66 @@
+use crate::components::get_i18n;
+use crate::core::{AppConfig, FolderWatcher, WatchEvent, WatchedFolder, start_watcher};
+use dioxus::prelude::*;
+use std::rc::Rc;
+use tokio::sync::{mpsc, watch};
+
+/// 后台自动上传的最新状态，通过 context 提供给各页面
+#[derive(Clone, Copy)]
+pub struct WatcherStatus(pub Signal<String>);
+
+/// 根据配置中的监控文件夹启动后台监控，文件夹列表变化时自动重启
+pub fn use_folder_watcher(config: Signal<AppConfig>) -> WatcherStatus {
+    let mut watch_status = use_signal(String::new);
+    let mut watcher = use_signal(|| None::<FolderWatcher>);
+    let mut watched = use_signal(Vec::<WatchedFolder>::new);
+    let config_tx = use_hook(|| Rc::new(watch::channel(config.peek().clone()).0));
+
+    use_effect(move || {
+        let current = config.read().clone();
+        config_tx.send_replace(current.clone());
+        if *watched.peek() == current.watched_folders {
+            return;
+        }
+
+        watched.set(current.watched_folders.clone());
+        watcher.set(None);
+        if current.watched_folders.is_empty() {
+            return;
+        }
+
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        match start_watcher(current.watched_folders, config_tx.subscribe(), tx) {
+            Ok(folder_watcher) => {
+                watcher.set(Some(folder_watcher));
+                spawn(async move {
+                    while let Some(event) = rx.recv().await {
+                        let i18n = get_i18n(&config);
+                        match event {
+                            WatchEvent::Queued { user, count } => {
+                                watch_status.set(i18n.auto_upload_queued(count, &user));
+                            }
+                            WatchEvent::Finished { report, .. } => {
+                                watch_status.set(i18n.auto_upload_finished(
+                                    report.uploaded.len(),
+                                    report.skipped,
+                                    report.failed.len(),
+                                ));
+                            }
+                            WatchEvent::Error { user, error } => {
+                                watch_status.set(i18n.auto_upload_error(&user, &error));
+                            }
+                            WatchEvent::Upload(_) => {}
+                        }
+                    }
+                });
+            }
+            Err(e) => {
+                tracing::error!("Failed to start folder watcher: {}", e);
+                let i18n = get_i18n(&config);
+                watch_status.set(i18n.watcher_start_failed(&e.to_string()));
+            }
+        }
+    });
+
+    WatcherStatus(watch_status)
+}
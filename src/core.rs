@@ -1,10 +1,49 @@
+mod batch;
 mod config;
+mod history;
 mod i18n;
 mod immich;
+mod ledger;
 mod log;
+mod media;
+mod metadata;
+mod progress;
+mod queue;
+mod retry;
+mod secrets;
+#[cfg(test)]
+mod test_util;
+mod throttle;
+mod thumbnail;
+mod watcher;
 
 // 只导出常用的核心类型，避免命名空间污染
-pub use config::{AppConfig, UserConfig, load_config, save_config};
+pub use batch::{
+    BatchControl, BatchState, UploadEvent, UploadFailure, UploadJob, UploadReport, run_upload,
+};
+pub use config::{
+    AppConfig, ServerProfile, UserConfig, WatchedFolder, delete_api_key, load_config, save_config,
+    validate_server_url,
+};
+pub use history::{HistoryEntry, UploadOutcome, load_history};
 pub use i18n::{I18n, Language};
-pub use immich::upload_asst;
+pub use immich::{
+    AccountInfo, Album, AlbumAssetResult, ApiError, AssetUpload, AssetUploadResponse,
+    BulkUploadCheckItem, BulkUploadCheckResult, ConnectionInfo, DuplicateCheck, ImmichClient,
+    ServerVersion, UploadError, check_duplicates, compute_checksum, find_or_create_album,
+    test_connection, upload_asst,
+};
 pub use log::init_logger;
+pub use media::{
+    MediaScan, MediaSummary, MediaType, SUPPORTED_EXTENSIONS, SortKey, collect_media_files,
+    extension_of, find_sidecar, is_supported_media, media_type, pair_live_photos, sort_media_files,
+    summarize_media,
+};
+pub use metadata::{CaptureSource, CaptureTime, read_capture_time, read_content_identifier};
+pub use progress::{BatchProgress, FileProgress};
+pub use queue::{UploadQueue, clear_queue, load_queue, save_queue};
+pub use retry::RetryConfig;
+pub use secrets::{PASSPHRASE_ENV, secret_store};
+pub use throttle::{BandwidthConfig, TimeWindow, set_bandwidth};
+pub use thumbnail::{FilePreview, load_preview, prune_thumbnail_cache};
+pub use watcher::{FolderWatcher, WatchEvent, start_watcher, validate_watched_folder};
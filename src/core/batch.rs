@@ -0,0 +1,577 @@
+use crate::core::history::{HistoryEntry, UploadOutcome, append_history};
+use crate::core::immich::{
+    ImmichClient, ProgressFn, UploadError, add_assets_to_album, check_duplicates, upload_asst,
+};
+use crate::core::ledger::UploadLedger;
+use crate::core::media::pair_live_photos;
+use crate::core::retry::RetryConfig;
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use tokio::sync::{Semaphore, mpsc, oneshot, watch};
+use tokio::task::JoinSet;
+use tokio_util::sync::CancellationToken;
+
+/// 批量上传任务
+#[derive(Debug, Clone)]
+pub struct UploadJob {
+    /// 上传账户对应的客户端，所有文件共享同一个连接池
+    pub client: ImmichClient,
+    /// 上传账户名称，记录在上传历史中
+    pub user: String,
+    pub concurrency: usize,
+    pub files: Vec<PathBuf>,
+    /// 出错时是否继续上传剩余文件
+    pub continue_on_error: bool,
+    /// 单个文件的重试策略
+    pub retry: RetryConfig,
+    /// 上传后加入的相册 ID
+    pub album_id: Option<String>,
+    /// 忽略本地上传记录，重新上传所有文件
+    pub force: bool,
+    /// 暂停、继续或取消本次上传
+    pub control: BatchControl,
+}
+
+/// 批量上传的控制句柄，克隆后共享同一状态
+#[derive(Debug, Clone)]
+pub struct BatchControl {
+    paused: Arc<watch::Sender<bool>>,
+    cancel: CancellationToken,
+}
+
+impl Default for BatchControl {
+    fn default() -> Self {
+        Self {
+            paused: Arc::new(watch::Sender::new(false)),
+            cancel: CancellationToken::new(),
+        }
+    }
+}
+
+impl BatchControl {
+    /// 暂停：正在上传的文件继续完成，不再开始新的文件
+    pub fn pause(&self) {
+        self.paused.send_replace(true);
+    }
+
+    pub fn resume(&self) {
+        self.paused.send_replace(false);
+    }
+
+    /// 取消：中止正在上传的文件，未完成的文件标记为待上传
+    pub fn cancel(&self) {
+        self.cancel.cancel();
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancel.is_cancelled()
+    }
+
+    /// 等待恢复上传，已取消时立即返回
+    async fn wait_resumed(&self) {
+        let mut paused = self.paused.subscribe();
+        tokio::select! {
+            _ = paused.wait_for(|paused| !paused) => {}
+            _ = self.cancel.cancelled() => {}
+        }
+    }
+}
+
+/// 界面展示的批量上传状态
+#[derive(Debug, Clone, Default)]
+pub enum BatchState {
+    #[default]
+    Idle,
+    Running(BatchControl),
+    Paused(BatchControl),
+    /// 已请求取消，等待正在上传的文件中止
+    Cancelling,
+}
+
+impl BatchState {
+    /// 是否有正在进行的批次
+    pub fn is_active(&self) -> bool {
+        !matches!(self, Self::Idle)
+    }
+}
+
+/// 批量上传过程中产生的事件，供界面或命令行展示进度
+#[derive(Debug, Clone)]
+pub enum UploadEvent {
+    /// 开始检查重复文件
+    CheckingDuplicates { total: usize },
+    /// 去重完成，开始上传
+    Started {
+        total: usize,
+        /// 跳过的文件数，包含 `already_uploaded`
+        skipped: usize,
+        /// 本地记录显示已上传而跳过的文件数，其余跳过的文件在服务器上已存在
+        already_uploaded: usize,
+        total_bytes: u64,
+    },
+    /// 本地记录或服务器上已存在而跳过的文件
+    Skipped { paths: Vec<PathBuf> },
+    /// 单个文件的字节级上传进度
+    Progress {
+        path: PathBuf,
+        file_name: String,
+        sent: u64,
+        total: u64,
+    },
+    /// 单个文件上传成功
+    Uploaded {
+        path: PathBuf,
+        file_name: String,
+        finished: usize,
+        total: usize,
+    },
+    /// 单个文件上传失败
+    Failed {
+        path: PathBuf,
+        file_name: String,
+        error: UploadError,
+    },
+    /// 批次被取消，这些文件未上传
+    Cancelled { paths: Vec<PathBuf> },
+}
+
+/// 上传失败的文件
+#[derive(Debug, Clone, PartialEq)]
+pub struct UploadFailure {
+    pub path: PathBuf,
+    pub file_name: String,
+    pub error: UploadError,
+}
+
+/// 批量上传结果
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct UploadReport {
+    /// 成功上传的文件
+    pub uploaded: Vec<PathBuf>,
+    /// 因重复而跳过的文件数
+    pub skipped: usize,
+    /// 其中本地记录显示已上传的文件数
+    pub already_uploaded: usize,
+    /// 失败的文件及错误信息
+    pub failed: Vec<UploadFailure>,
+    /// 因中止而未上传的文件
+    pub aborted: Vec<PathBuf>,
+    /// 是否由用户取消
+    pub cancelled: bool,
+}
+
+impl UploadReport {
+    pub fn is_success(&self) -> bool {
+        self.failed.is_empty() && self.aborted.is_empty()
+    }
+
+    /// 需要重新上传的文件：失败的文件和因中止而未上传的文件
+    pub fn retry_paths(&self) -> Vec<PathBuf> {
+        self.failed
+            .iter()
+            .map(|failure| failure.path.clone())
+            .chain(self.aborted.iter().cloned())
+            .collect()
+    }
+}
+
+/// 将上传结果写入历史记录，写入失败只记录日志
+struct HistoryRecorder {
+    user: String,
+    server: String,
+}
+
+impl HistoryRecorder {
+    fn entry(
+        &self,
+        path: &Path,
+        checksum: Option<String>,
+        outcome: UploadOutcome,
+        asset_id: Option<String>,
+        error: Option<UploadError>,
+    ) -> HistoryEntry {
+        HistoryEntry {
+            timestamp: chrono::Utc::now(),
+            path: path.to_path_buf(),
+            checksum,
+            size: std::fs::metadata(path).map(|m| m.len()).unwrap_or_default(),
+            user: self.user.clone(),
+            server: self.server.clone(),
+            asset_id,
+            outcome,
+            error,
+        }
+    }
+
+    fn record(&self, entries: &[HistoryEntry]) {
+        if let Err(e) = append_history(entries) {
+            tracing::warn!("Failed to write upload history: {}", e);
+        }
+    }
+}
+
+fn file_name_of(path: &Path) -> String {
+    path.file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_default()
+}
+
+/// 执行批量上传：先通过校验和过滤重复文件，再按并发数上传
+///
+/// Live Photo 的视频先上传，图片上传时通过 `livePhotoVideoId` 关联视频；
+/// 未开启 `continue_on_error` 时，遇到第一个错误即中止剩余任务；
+/// 取消时中止所有未完成的任务，并通过 `UploadEvent::Cancelled` 通知
+pub async fn run_upload(
+    job: UploadJob,
+    events: mpsc::UnboundedSender<UploadEvent>,
+) -> UploadReport {
+    let UploadJob {
+        client,
+        user,
+        concurrency,
+        files,
+        continue_on_error,
+        retry,
+        album_id,
+        force,
+        control,
+    } = job;
+    let concurrency = concurrency.max(1);
+    let history = HistoryRecorder {
+        user,
+        server: client.base_url().to_string(),
+    };
+
+    // Live Photo 配对需要读取元数据，在后台线程中进行
+    let live_photos = {
+        let files = files.clone();
+        tokio::task::spawn_blocking(move || pair_live_photos(&files))
+            .await
+            .unwrap_or_else(|e| {
+                tracing::warn!("Live Photo pairing task failed: {}", e);
+                HashMap::new()
+            })
+    };
+    if !live_photos.is_empty() {
+        tracing::info!("Found {} Live Photo pairs", live_photos.len());
+    }
+
+    let mut ledger = UploadLedger::load().unwrap_or_else(|e| {
+        tracing::warn!("Failed to load upload ledger: {}", e);
+        UploadLedger::default()
+    });
+    // 本地记录中已上传且未修改的文件直接跳过，无需计算校验和；强制上传时忽略记录
+    let mut existing_assets = HashMap::new();
+    let mut already_uploaded = Vec::new();
+    let files: Vec<PathBuf> = if force {
+        files
+    } else {
+        files
+            .into_iter()
+            .filter(
+                |path| match ledger.lookup(&history.server, &history.user, path) {
+                    Some(asset_id) => {
+                        existing_assets.insert(path.clone(), asset_id);
+                        already_uploaded.push(path.clone());
+                        false
+                    }
+                    None => true,
+                },
+            )
+            .collect()
+    };
+    let already_uploaded_count = already_uploaded.len();
+    if already_uploaded_count > 0 {
+        tracing::info!(
+            "Skipping {} files already uploaded according to the local ledger",
+            already_uploaded_count
+        );
+        let _ = events.send(UploadEvent::Skipped {
+            paths: already_uploaded,
+        });
+    }
+
+    let _ = events.send(UploadEvent::CheckingDuplicates { total: files.len() });
+    let (paths, duplicates, checksums) =
+        match check_duplicates(&client, files.clone(), concurrency).await {
+            Ok(check) => {
+                let entries: Vec<HistoryEntry> = check
+                    .duplicates
+                    .iter()
+                    .map(|path| {
+                        history.entry(
+                            path,
+                            check.checksums.get(path).cloned(),
+                            UploadOutcome::Duplicate,
+                            check.duplicate_asset_ids.get(path).cloned(),
+                            None,
+                        )
+                    })
+                    .collect();
+                history.record(&entries);
+                let known: Vec<(PathBuf, String)> = check.duplicate_asset_ids.into_iter().collect();
+                if let Err(e) = ledger.record(&history.server, &history.user, &known) {
+                    tracing::warn!("Failed to update upload ledger: {}", e);
+                }
+                existing_assets.extend(known);
+                let duplicates = check.duplicates.len();
+                if duplicates > 0 {
+                    let _ = events.send(UploadEvent::Skipped {
+                        paths: check.duplicates,
+                    });
+                }
+                (check.to_upload, duplicates, check.checksums)
+            }
+            Err(e) => {
+                tracing::warn!("Duplicate check failed, uploading all files: {}", e);
+                (files, 0, HashMap::new())
+            }
+        };
+    let skipped = already_uploaded_count + duplicates;
+
+    // 已存在的文件同样加入相册
+    let existing_asset_ids: Vec<String> = existing_assets.values().cloned().collect();
+    if let Some(album_id) = &album_id
+        && let Err(e) = add_assets_to_album(&client, album_id, &existing_asset_ids).await
+    {
+        tracing::warn!("Failed to add existing assets to album: {}", e);
+    }
+    let total_files = paths.len();
+    let total_bytes = paths
+        .iter()
+        .filter_map(|path| std::fs::metadata(path).ok())
+        .map(|metadata| metadata.len())
+        .sum();
+
+    tracing::info!(
+        "Starting batch upload: {} files with concurrency {}, {} already uploaded, {} duplicates skipped",
+        total_files,
+        concurrency,
+        already_uploaded_count,
+        duplicates
+    );
+    let _ = events.send(UploadEvent::Started {
+        total: total_files,
+        skipped,
+        already_uploaded: already_uploaded_count,
+        total_bytes,
+    });
+
+    // 图片通过 oneshot 等待视频的资源 ID，视频已存在于服务器时直接使用已有 ID
+    let mut video_senders = HashMap::new();
+    let mut video_receivers = HashMap::new();
+    {
+        let pending: HashSet<&PathBuf> = paths.iter().collect();
+        for (image, video) in &live_photos {
+            if !pending.contains(image) {
+                continue;
+            }
+            let (sender, receiver) = oneshot::channel();
+            if pending.contains(video) {
+                video_senders.insert(video.clone(), sender);
+            } else if let Some(asset_id) = existing_assets.get(video) {
+                let _ = sender.send(asset_id.clone());
+            } else {
+                continue;
+            }
+            video_receivers.insert(image.clone(), receiver);
+        }
+    }
+
+    let mut tasks = JoinSet::new();
+    let mut task_paths = HashMap::new();
+    let semaphore = Arc::new(Semaphore::new(concurrency));
+
+    for path in paths {
+        let semaphore = semaphore.clone();
+        let client = client.clone();
+        let retry = retry.clone();
+        let album_id = album_id.clone();
+        let checksum = checksums.get(&path).cloned();
+        let task_path = path.clone();
+        let progress_events = events.clone();
+        let progress_path = path.clone();
+        let progress_name = file_name_of(&path);
+        let control = control.clone();
+        let video_sender = video_senders.remove(&path);
+        let video_receiver = video_receivers.remove(&path);
+        let on_progress: ProgressFn = Arc::new(move |sent, total| {
+            let _ = progress_events.send(UploadEvent::Progress {
+                path: progress_path.clone(),
+                file_name: progress_name.clone(),
+                sent,
+                total,
+            });
+        });
+
+        let handle = tasks.spawn(async move {
+            // 在获取并发许可之前等待视频，避免占用视频上传所需的许可
+            let live_photo_video_id = match video_receiver {
+                Some(receiver) => receiver
+                    .await
+                    .inspect_err(|_| {
+                        tracing::debug!(
+                            "Live Photo video for {:?} was not uploaded, uploading the still alone",
+                            task_path
+                        )
+                    })
+                    .ok(),
+                None => None,
+            };
+            let _permit = semaphore.acquire_owned().await;
+            // 暂停期间不开始新的文件
+            control.wait_resumed().await;
+            // 已取消时不再开始上传，返回 None
+            if control.is_cancelled() {
+                return None;
+            }
+            let result = async {
+                let asset_id = upload_asst(
+                    &client,
+                    task_path,
+                    checksum,
+                    live_photo_video_id,
+                    &retry,
+                    Some(on_progress),
+                )
+                .await?;
+                if let Some(sender) = video_sender {
+                    let _ = sender.send(asset_id.clone());
+                }
+                // 加入相册失败不影响文件本身的上传结果
+                if let Some(album_id) = &album_id
+                    && let Err(e) =
+                        add_assets_to_album(&client, album_id, std::slice::from_ref(&asset_id))
+                            .await
+                {
+                    tracing::warn!("Failed to add asset to album {}: {}", album_id, e);
+                }
+                Ok::<_, UploadError>(asset_id)
+            };
+            Some(result.await)
+        });
+        task_paths.insert(handle.id(), path);
+    }
+
+    let mut report = UploadReport {
+        skipped,
+        already_uploaded: already_uploaded_count,
+        ..Default::default()
+    };
+
+    loop {
+        let next = tokio::select! {
+            biased;
+            _ = control.cancel.cancelled(), if !report.cancelled => {
+                tracing::info!("Cancelling batch upload, {} files not finished", task_paths.len());
+                // 已完成但尚未处理的任务仍按正常结果记录，其余任务返回取消错误
+                tasks.abort_all();
+                report.cancelled = true;
+                continue;
+            }
+            next = tasks.join_next_with_id() => next,
+        };
+        let Some(result) = next else {
+            break;
+        };
+        let (id, result) = match result {
+            Ok((id, Some(result))) => (id, result),
+            Ok((id, None)) => {
+                report.aborted.extend(task_paths.remove(&id));
+                continue;
+            }
+            Err(e) if e.is_cancelled() => {
+                report.aborted.extend(task_paths.remove(&e.id()));
+                continue;
+            }
+            Err(e) => {
+                tracing::error!("Task execution failed: {}", e);
+                let error = UploadError::Other(format!("Task execution failed: {}", e));
+                (e.id(), Err(error))
+            }
+        };
+        let path = task_paths.remove(&id).unwrap_or_default();
+        let file_name = file_name_of(&path);
+        let checksum = checksums.get(&path).cloned();
+
+        match result {
+            Ok(asset_id) => {
+                history.record(&[history.entry(
+                    &path,
+                    checksum,
+                    UploadOutcome::Uploaded,
+                    Some(asset_id.clone()),
+                    None,
+                )]);
+                if let Err(e) =
+                    ledger.record(&history.server, &history.user, &[(path.clone(), asset_id)])
+                {
+                    tracing::warn!("Failed to update upload ledger: {}", e);
+                }
+                report.uploaded.push(path.clone());
+                tracing::debug!(
+                    "File uploaded successfully ({}/{}): {}",
+                    report.uploaded.len(),
+                    total_files,
+                    file_name
+                );
+                let _ = events.send(UploadEvent::Uploaded {
+                    path,
+                    file_name,
+                    finished: report.uploaded.len(),
+                    total: total_files,
+                });
+            }
+            Err(e) => {
+                tracing::error!("File upload failed: {} - {}", file_name, e);
+                history.record(&[history.entry(
+                    &path,
+                    checksum,
+                    UploadOutcome::Failed,
+                    None,
+                    Some(e.clone()),
+                )]);
+                let _ = events.send(UploadEvent::Failed {
+                    path: path.clone(),
+                    file_name: file_name.clone(),
+                    error: e.clone(),
+                });
+                report.failed.push(UploadFailure {
+                    path,
+                    file_name,
+                    error: e,
+                });
+                // 与取消相同，已完成但尚未处理的任务继续按正常结果记录，只有被中止的任务计入 aborted
+                if !continue_on_error {
+                    tasks.abort_all();
+                }
+            }
+        }
+    }
+
+    report.aborted.sort();
+    if report.cancelled {
+        let _ = events.send(UploadEvent::Cancelled {
+            paths: report.aborted.clone(),
+        });
+    }
+
+    if report.is_success() {
+        tracing::info!(
+            "All files uploaded successfully: {} files, {} duplicates skipped",
+            report.uploaded.len(),
+            report.skipped
+        );
+    } else {
+        tracing::warn!(
+            "Batch upload finished: {} uploaded, {} failed, {} aborted, {} duplicates skipped",
+            report.uploaded.len(),
+            report.failed.len(),
+            report.aborted.len(),
+            report.skipped
+        );
+    }
+
+    report
+}
@@ -1,8 +1,14 @@
 use crate::core::i18n::Language;
+use crate::core::retry::RetryConfig;
+use crate::core::secrets::{
+    PASSPHRASE_ENV, SecretStore, delete_secret, resolve_secret, secret_store,
+};
+use crate::core::throttle::BandwidthConfig;
 use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::fs;
+use std::path::PathBuf;
 
 /// 应用配置结构
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -10,12 +16,27 @@ pub struct AppConfig {
     /// 当前用户名
     #[serde(default)]
     pub current_user: String,
-    /// 服务器地址
-    #[serde(default = "default_server_url")]
-    pub server_url: String,
+    /// 当前服务器配置名称
+    #[serde(default)]
+    pub current_profile: String,
+    /// 服务器配置，键为配置名称
+    #[serde(default)]
+    pub profiles: BTreeMap<String, ServerProfile>,
+    /// 旧版本的全局服务器地址，仅用于迁移
+    #[serde(default, rename = "server_url", skip_serializing)]
+    legacy_server_url: Option<String>,
     /// 并发数
     #[serde(default = "default_concurrency")]
     pub concurrency: u8,
+    /// 出错时继续上传剩余文件
+    #[serde(default)]
+    pub continue_on_error: bool,
+    /// 上传重试策略
+    #[serde(default)]
+    pub retry: RetryConfig,
+    /// 上传限速
+    #[serde(default)]
+    pub bandwidth: BandwidthConfig,
     /// 界面语言
     #[serde(default)]
     pub language: Language,
@@ -25,10 +46,9 @@ pub struct AppConfig {
     /// 用户列表
     #[serde(default)]
     pub users: HashMap<String, UserConfig>,
-}
-
-fn default_server_url() -> String {
-    "http://localhost:2283".to_string()
+    /// 自动上传的监控文件夹
+    #[serde(default)]
+    pub watched_folders: Vec<WatchedFolder>,
 }
 
 fn default_concurrency() -> u8 {
@@ -43,19 +63,189 @@ impl Default for AppConfig {
     fn default() -> Self {
         Self {
             current_user: String::new(),
-            server_url: "http://localhost:2283".to_string(),
+            current_profile: DEFAULT_PROFILE.to_string(),
+            profiles: BTreeMap::from([(DEFAULT_PROFILE.to_string(), ServerProfile::default())]),
+            legacy_server_url: None,
             concurrency: 5,
+            continue_on_error: false,
+            retry: RetryConfig::default(),
+            bandwidth: BandwidthConfig::default(),
             language: Language::default(),
             log_level: "info".to_string(),
             users: HashMap::new(),
+            watched_folders: Vec::new(),
         }
     }
 }
 
+impl AppConfig {
+    /// 当前选中的服务器配置
+    pub fn current_server(&self) -> Option<&ServerProfile> {
+        self.profiles.get(&self.current_profile)
+    }
+
+    /// 用户所属的服务器配置
+    pub fn server_for_user(&self, username: &str) -> Option<&ServerProfile> {
+        self.users
+            .get(username)
+            .and_then(|user| self.profiles.get(&user.profile))
+    }
+
+    /// 属于指定服务器配置的用户，按名称排序
+    pub fn profile_users(&self, profile: &str) -> Vec<String> {
+        let mut users: Vec<_> = self
+            .users
+            .iter()
+            .filter(|(_, user)| user.profile == profile)
+            .map(|(username, _)| username.clone())
+            .collect();
+        users.sort();
+        users
+    }
+
+    /// 将旧版本的单一 server_url 迁移为服务器配置，返回配置是否被修改
+    fn migrate_profiles(&mut self) -> bool {
+        let mut changed = false;
+        if self.profiles.is_empty() {
+            let url = self
+                .legacy_server_url
+                .take()
+                .unwrap_or_else(|| ServerProfile::default().url);
+            tracing::info!(
+                "Migrating server URL {} to profile {}",
+                url,
+                DEFAULT_PROFILE
+            );
+            self.profiles.insert(
+                DEFAULT_PROFILE.to_string(),
+                ServerProfile {
+                    url,
+                    ..Default::default()
+                },
+            );
+            changed = true;
+        }
+        if !self.profiles.contains_key(&self.current_profile) {
+            self.current_profile = self.profiles.keys().next().cloned().unwrap_or_default();
+            changed = true;
+        }
+        for user in self.users.values_mut() {
+            if user.profile.is_empty() {
+                user.profile = self.current_profile.clone();
+                changed = true;
+            }
+        }
+        changed
+    }
+}
+
+/// 旧配置迁移时使用的服务器配置名称
+const DEFAULT_PROFILE: &str = "Default";
+
+/// 服务器配置
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ServerProfile {
+    /// 服务器地址
+    pub url: String,
+    /// 每个请求附加的请求头，例如反向代理的认证信息
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub headers: BTreeMap<String, String>,
+    /// 是否接受无效证书（例如自签名证书）
+    #[serde(default)]
+    pub accept_invalid_certs: bool,
+    /// 额外信任的 CA 证书（PEM 格式）
+    #[serde(default)]
+    pub ca_certificate: Option<PathBuf>,
+}
+
+impl Default for ServerProfile {
+    fn default() -> Self {
+        Self {
+            url: "http://localhost:2283".to_string(),
+            headers: BTreeMap::new(),
+            accept_invalid_certs: false,
+            ca_certificate: None,
+        }
+    }
+}
+
+/// 检查服务器地址是否为合法的 http/https URL
+pub fn validate_server_url(url: &str) -> Result<()> {
+    let parsed = reqwest::Url::parse(url.trim()).context("Invalid URL")?;
+    if !matches!(parsed.scheme(), "http" | "https") {
+        anyhow::bail!("URL must start with http:// or https://");
+    }
+    if parsed.host_str().is_none_or(str::is_empty) {
+        anyhow::bail!("URL has no host");
+    }
+    if parsed.query().is_some() || parsed.fragment().is_some() {
+        anyhow::bail!("URL must not contain a query or fragment");
+    }
+    Ok(())
+}
+
 /// 用户配置结构
 #[derive(Debug, Serialize, Deserialize, Clone, Default)]
 pub struct UserConfig {
+    /// 所属的服务器配置名称
+    #[serde(default)]
+    pub profile: String,
+    /// API Key，保存时会移入密钥存储，不会以明文写入配置文件
+    #[serde(default, skip_serializing_if = "String::is_empty")]
     pub api_key: String,
+    /// API Key 在密钥存储中的引用，格式为 `后端:用户名`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub api_key_ref: Option<String>,
+    /// 从密钥存储读取 API Key 失败的原因，不写入配置文件
+    #[serde(skip)]
+    pub api_key_error: Option<String>,
+    /// 加载时已保存的 API Key，未改动的 Key 保存时不需要访问密钥存储
+    #[serde(skip)]
+    stored_api_key: Option<String>,
+}
+
+impl UserConfig {
+    /// 新添加的用户，API Key 在保存时写入密钥存储
+    pub fn new(profile: String, api_key: String) -> Self {
+        Self {
+            profile,
+            api_key,
+            ..Default::default()
+        }
+    }
+
+    /// 上传使用的 API Key，缺失或密钥存储无法读取（例如未解锁）时返回错误
+    pub fn require_api_key(&self) -> Result<&str> {
+        if !self.api_key.trim().is_empty() {
+            return Ok(&self.api_key);
+        }
+        match &self.api_key_error {
+            Some(error) => anyhow::bail!("API Key is missing or locked: {}", error),
+            None => anyhow::bail!("API Key is missing"),
+        }
+    }
+}
+
+/// 监控文件夹配置
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct WatchedFolder {
+    /// 监控的目录
+    pub path: PathBuf,
+    /// 新文件上传到的用户
+    pub user: String,
+    /// 是否监控子目录
+    #[serde(default = "default_recursive")]
+    pub recursive: bool,
+    /// 只上传匹配这些 glob 的文件（相对于监控目录），为空时上传所有支持的文件
+    #[serde(default)]
+    pub include: Vec<String>,
+    /// 忽略匹配这些 glob 的文件（相对于监控目录）
+    #[serde(default)]
+    pub exclude: Vec<String>,
+}
+
+fn default_recursive() -> bool {
+    true
 }
 
 /// 从配置文件加载配置，不存在时创建默认配置
@@ -79,19 +269,304 @@ pub fn load_config() -> Result<AppConfig> {
 
     tracing::debug!("Loading config from: {:?}", config_path);
     let content = fs::read_to_string(&config_path).context("Failed to read config file")?;
-    let config: AppConfig = toml::from_str(&content).context("Failed to parse config")?;
+    let mut config: AppConfig = toml::from_str(&content).context("Failed to parse config")?;
+
+    let migrated = config.migrate_profiles();
+    let mut has_plaintext_keys = false;
+    for (username, user) in config.users.iter_mut() {
+        match &user.api_key_ref {
+            Some(reference) => match resolve_secret(reference) {
+                Ok(Some(api_key)) => {
+                    user.stored_api_key = Some(api_key.clone());
+                    user.api_key = api_key;
+                }
+                Ok(None) => {
+                    tracing::warn!("API Key not found in secret store for {}", username);
+                    user.api_key_error = Some(format!("not found in secret store ({})", reference));
+                }
+                Err(e) => {
+                    tracing::warn!("Failed to load API Key for {}: {:#}", username, e);
+                    user.api_key_error = Some(format!("{:#}", e));
+                }
+            },
+            None if !user.api_key.is_empty() => {
+                has_plaintext_keys = true;
+                user.stored_api_key = Some(user.api_key.clone());
+            }
+            None => {}
+        }
+    }
+
+    // 将旧版本保存的明文 API Key 迁移到密钥存储；密钥存储不可用时保留明文，下次启动再迁移
+    if (has_plaintext_keys && secret_store().is_some()) || migrated {
+        if has_plaintext_keys {
+            tracing::info!("Migrating plaintext API keys to secret store");
+        }
+        if let Err(e) = save_config(&mut config) {
+            tracing::warn!("Failed to save migrated config: {:#}", e);
+        }
+    }
     Ok(config)
 }
 
-/// 保存配置到文件
-pub fn save_config(config: &AppConfig) -> Result<()> {
+/// 将新增或修改的 API Key 写入密钥存储，并把引用记录到配置中
+///
+/// 只有新增或修改的 API Key 需要密钥存储，其不可用或写入失败时返回错误，不会以明文保存；
+/// 旧版本的明文 API Key 在密钥存储不可用时原样保留
+fn store_api_keys<'a>(
+    config: &mut AppConfig,
+    store: impl Fn() -> Option<&'a dyn SecretStore>,
+) -> Result<()> {
+    for (username, user) in config.users.iter_mut() {
+        if user.api_key.is_empty() {
+            continue;
+        }
+        let unchanged = user.stored_api_key.as_ref() == Some(&user.api_key);
+        if unchanged && user.api_key_ref.is_some() {
+            continue;
+        }
+        let result = match store() {
+            Some(store) => store
+                .set(username, &user.api_key)
+                .map(|()| format!("{}:{}", store.name(), username)),
+            None => Err(anyhow::anyhow!(
+                "no system keyring is available. Set {} and restart to store keys in an encrypted file",
+                PASSPHRASE_ENV
+            )),
+        };
+        match result {
+            Ok(reference) => {
+                user.api_key_ref = Some(reference);
+                user.stored_api_key = Some(user.api_key.clone());
+            }
+            Err(e) if unchanged => {
+                tracing::warn!(
+                    "Keeping plaintext API Key for {} until a secret store is available: {:#}",
+                    username,
+                    e
+                );
+            }
+            Err(e) => anyhow::bail!("Cannot save the API Key for {}: {:#}", username, e),
+        }
+    }
+    Ok(())
+}
+
+/// 写入配置文件的内容，已保存到密钥存储的 API Key 只保留引用
+fn serialize_config(config: &AppConfig) -> Result<String> {
+    let mut stored = config.clone();
+    for user in stored.users.values_mut() {
+        if user.api_key_ref.is_some() {
+            user.api_key.clear();
+        }
+    }
+    toml::to_string(&stored).context("Failed to serialize config")
+}
+
+/// 从密钥存储中删除用户的 API Key
+pub fn delete_api_key(user: &UserConfig) -> Result<()> {
+    match &user.api_key_ref {
+        Some(reference) => delete_secret(reference),
+        None => Ok(()),
+    }
+}
+
+/// 保存配置到文件，新写入密钥存储的 API Key 引用会同步更新到传入的配置中
+pub fn save_config(config: &mut AppConfig) -> Result<()> {
     let home = dirs::home_dir().context("Failed to get home directory")?;
     let config_dir = home.join(".immich");
     let config_path = config_dir.join("config.toml");
 
     fs::create_dir_all(&config_dir).context("Failed to create config directory")?;
-    let toml_content = toml::to_string(config).context("Failed to serialize config")?;
+    store_api_keys(config, secret_store)?;
+    let toml_content = serialize_config(config)?;
     fs::write(&config_path, toml_content).context("Failed to write config file")?;
     tracing::debug!("Config saved to: {:?}", config_path);
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    /// 记录写入次数的内存密钥存储
+    #[derive(Default)]
+    struct MemoryStore {
+        secrets: Mutex<BTreeMap<String, String>>,
+        writes: Mutex<usize>,
+    }
+
+    impl SecretStore for MemoryStore {
+        fn name(&self) -> &'static str {
+            "memory"
+        }
+
+        fn get(&self, account: &str) -> Result<Option<String>> {
+            Ok(self.secrets.lock().unwrap().get(account).cloned())
+        }
+
+        fn set(&self, account: &str, secret: &str) -> Result<()> {
+            *self.writes.lock().unwrap() += 1;
+            self.secrets
+                .lock()
+                .unwrap()
+                .insert(account.to_string(), secret.to_string());
+            Ok(())
+        }
+
+        fn delete(&self, account: &str) -> Result<()> {
+            self.secrets.lock().unwrap().remove(account);
+            Ok(())
+        }
+    }
+
+    fn config_with_user(user: UserConfig) -> AppConfig {
+        let mut config = AppConfig::default();
+        config.users.insert("alice".to_string(), user);
+        config
+    }
+
+    #[test]
+    fn stores_new_key_once() {
+        let store = MemoryStore::default();
+        let mut config = config_with_user(UserConfig::new(
+            DEFAULT_PROFILE.to_string(),
+            "secret".to_string(),
+        ));
+
+        store_api_keys(&mut config, || Some(&store)).unwrap();
+        let user = &config.users["alice"];
+        assert_eq!(user.api_key_ref.as_deref(), Some("memory:alice"));
+        assert_eq!(user.stored_api_key.as_deref(), Some("secret"));
+        // 内存中的 Key 仍可用于上传，配置文件中只保存引用
+        assert_eq!(user.require_api_key().unwrap(), "secret");
+        let content = serialize_config(&config).unwrap();
+        assert!(!content.contains("\"secret\""), "{content}");
+        assert!(content.contains("memory:alice"), "{content}");
+
+        store_api_keys(&mut config, || Some(&store)).unwrap();
+        assert_eq!(*store.writes.lock().unwrap(), 1);
+        assert_eq!(store.get("alice").unwrap().as_deref(), Some("secret"));
+    }
+
+    #[test]
+    fn migrates_plaintext_key_once() {
+        let store = MemoryStore::default();
+        let mut config: AppConfig = toml::from_str(
+            r#"
+            [users.alice]
+            profile = "Default"
+            api_key = "legacy"
+            "#,
+        )
+        .unwrap();
+        let user = config.users.get_mut("alice").unwrap();
+        user.stored_api_key = Some(user.api_key.clone());
+
+        store_api_keys(&mut config, || Some(&store)).unwrap();
+        assert_eq!(
+            config.users["alice"].api_key_ref.as_deref(),
+            Some("memory:alice")
+        );
+        store_api_keys(&mut config, || Some(&store)).unwrap();
+        assert_eq!(*store.writes.lock().unwrap(), 1);
+    }
+
+    #[test]
+    fn keeps_plaintext_key_without_store() {
+        let mut user = UserConfig::new(DEFAULT_PROFILE.to_string(), "legacy".to_string());
+        user.stored_api_key = Some("legacy".to_string());
+        let mut config = config_with_user(user);
+
+        store_api_keys(&mut config, || None).unwrap();
+        assert!(config.users["alice"].api_key_ref.is_none());
+        assert!(serialize_config(&config).unwrap().contains("legacy"));
+    }
+
+    #[test]
+    fn rejects_new_key_without_store() {
+        let mut config = config_with_user(UserConfig::new(
+            DEFAULT_PROFILE.to_string(),
+            "secret".to_string(),
+        ));
+        assert!(store_api_keys(&mut config, || None).is_err());
+    }
+
+    #[test]
+    fn migrates_legacy_server_url() {
+        let mut config: AppConfig = toml::from_str(
+            r#"
+            current_user = "alice"
+            server_url = "http://192.168.1.100:2283"
+
+            [users.alice]
+            api_key = "secret"
+            "#,
+        )
+        .unwrap();
+
+        assert!(config.migrate_profiles());
+        assert_eq!(config.current_profile, DEFAULT_PROFILE);
+        assert_eq!(
+            config.current_server().unwrap().url,
+            "http://192.168.1.100:2283"
+        );
+        assert_eq!(config.users["alice"].profile, DEFAULT_PROFILE);
+        assert_eq!(
+            config.server_for_user("alice").unwrap().url,
+            "http://192.168.1.100:2283"
+        );
+        // 旧字段不再写回配置文件
+        assert!(!serialize_config(&config).unwrap().contains("server_url"));
+        assert!(!config.migrate_profiles());
+    }
+
+    #[test]
+    fn fills_empty_user_profile() {
+        let mut config: AppConfig = toml::from_str(
+            r#"
+            current_profile = "Home"
+
+            [profiles.Home]
+            url = "http://home:2283"
+
+            [profiles.Work]
+            url = "http://work:2283"
+
+            [users.alice]
+            api_key = "a"
+
+            [users.bob]
+            profile = "Work"
+            api_key = "b"
+            "#,
+        )
+        .unwrap();
+
+        assert!(config.migrate_profiles());
+        assert_eq!(config.users["alice"].profile, "Home");
+        assert_eq!(config.users["bob"].profile, "Work");
+        assert_eq!(config.profile_users("Home"), ["alice"]);
+    }
+
+    #[test]
+    fn fixes_invalid_current_profile() {
+        let mut config: AppConfig = toml::from_str(
+            r#"
+            current_profile = "Missing"
+
+            [profiles.Work]
+            url = "http://work:2283"
+
+            [profiles.Home]
+            url = "http://home:2283"
+            "#,
+        )
+        .unwrap();
+
+        assert!(config.migrate_profiles());
+        assert_eq!(config.current_profile, "Home");
+        assert!(!config.migrate_profiles());
+    }
+}
@@ -0,0 +1,135 @@
+use crate::core::immich::UploadError;
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fs::{self, OpenOptions};
+use std::io::{BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+
+/// 单个文件的上传结果
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum UploadOutcome {
+    Uploaded,
+    /// 服务器上已存在，未重复上传
+    Duplicate,
+    Failed,
+}
+
+/// 上传历史中的一条记录
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HistoryEntry {
+    pub timestamp: DateTime<Utc>,
+    pub path: PathBuf,
+    /// 文件内容的 SHA-1 校验和，计算失败时为空
+    #[serde(default)]
+    pub checksum: Option<String>,
+    pub size: u64,
+    pub user: String,
+    pub server: String,
+    /// 服务器上的资源 ID
+    #[serde(default)]
+    pub asset_id: Option<String>,
+    pub outcome: UploadOutcome,
+    #[serde(default)]
+    pub error: Option<UploadError>,
+}
+
+/// 历史记录文件路径 (~/.immich/history.jsonl)
+fn history_path() -> Result<PathBuf> {
+    let home = dirs::home_dir().context("Failed to get home directory")?;
+    Ok(home.join(".immich").join("history.jsonl"))
+}
+
+/// 追加历史记录，每条记录占一行 JSON
+pub fn append_history(entries: &[HistoryEntry]) -> Result<()> {
+    if entries.is_empty() {
+        return Ok(());
+    }
+    append_entries(&history_path()?, entries)
+}
+
+fn append_entries(path: &Path, entries: &[HistoryEntry]) -> Result<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).context("Failed to create config directory")?;
+    }
+
+    let mut content = String::new();
+    for entry in entries {
+        content.push_str(&serde_json::to_string(entry).context("Failed to serialize history")?);
+        content.push('\n');
+    }
+    OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .and_then(|mut file| file.write_all(content.as_bytes()))
+        .context("Failed to write history file")
+}
+
+/// 读取全部历史记录，最新的在前，无法解析的行会被跳过
+pub fn load_history() -> Result<Vec<HistoryEntry>> {
+    read_entries(&history_path()?)
+}
+
+fn read_entries(path: &Path) -> Result<Vec<HistoryEntry>> {
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+
+    let file = fs::File::open(path).context("Failed to open history file")?;
+    let mut entries = Vec::new();
+    for (index, line) in BufReader::new(file).lines().enumerate() {
+        let line = line.context("Failed to read history file")?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        match serde_json::from_str(&line) {
+            Ok(entry) => entries.push(entry),
+            Err(e) => tracing::warn!("Skipping invalid history line {}: {}", index + 1, e),
+        }
+    }
+    entries.reverse();
+    Ok(entries)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::test_util::temp_path;
+
+    fn entry(path: &str, outcome: UploadOutcome) -> HistoryEntry {
+        HistoryEntry {
+            timestamp: Utc::now(),
+            path: PathBuf::from(path),
+            checksum: Some("abc".to_string()),
+            size: 42,
+            user: "alice".to_string(),
+            server: "http://localhost:2283".to_string(),
+            asset_id: None,
+            outcome,
+            error: (outcome == UploadOutcome::Failed).then_some(UploadError::Timeout),
+        }
+    }
+
+    #[test]
+    fn skips_malformed_lines() {
+        let path = temp_path("history.jsonl");
+        assert!(read_entries(&path).unwrap().is_empty());
+
+        let first = entry("/photos/a.jpg", UploadOutcome::Uploaded);
+        let second = entry("/photos/b.jpg", UploadOutcome::Failed);
+        append_entries(&path, std::slice::from_ref(&first)).unwrap();
+        // 写入中断的行、空行和旧格式的行都应被跳过
+        OpenOptions::new()
+            .append(true)
+            .open(&path)
+            .and_then(|mut file| file.write_all(b"{\"timestamp\": \n\n[1, 2]\nnot json\n"))
+            .unwrap();
+        append_entries(&path, std::slice::from_ref(&second)).unwrap();
+
+        // 最新的在前
+        assert_eq!(read_entries(&path).unwrap(), [second, first]);
+        let _ = fs::remove_file(&path);
+    }
+}
@@ -1,20 +1,16 @@
+use crate::core::immich::UploadError;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum Language {
     #[serde(rename = "zh")]
     Chinese,
     #[serde(rename = "en")]
+    #[default]
     English,
 }
 
-impl Default for Language {
-    fn default() -> Self {
-        Language::English
-    }
-}
-
 impl Language {
     pub fn code(&self) -> &'static str {
         match self {
@@ -38,6 +34,26 @@ pub enum TextKey {
     SelectUploadAccount,
     AddAccountInSettings,
     SelectFiles,
+    SelectFolder,
+    SelectFolders,
+    ScanningFolders,
+    FolderScanResult,
+    DropFilesHint,
+    FilesAdded,
+    SortFiles,
+    SortByName,
+    SortByDate,
+    SortBySize,
+    SortByType,
+    SortingFiles,
+    AllMediaTypes,
+    ImagesOnly,
+    VideosOnly,
+    SelectionTotals,
+    ShowingFiles,
+    RemoveShown,
+    RemoveFile,
+    SidecarAttached,
     Clear,
     StartUpload,
     Uploading,
@@ -45,14 +61,33 @@ pub enum TextKey {
     FilesToUpload,
     Ready,
     SwitchedToUser,
+    ServerProfile,
+    SwitchedToProfile,
     FilesSelected,
     SelectionCleared,
     UserApiKeyNotFound,
+    ApiKeyUnavailable,
     ConfigFileNotFound,
     StartParallelUpload,
     UploadSuccess,
     UploadFailed,
     AllFilesUploaded,
+    CheckingDuplicates,
+    UploadFinishedWithSkipped,
+    ContinueOnError,
+    ForceUpload,
+    ForceUploadHint,
+    Album,
+    AlbumPlaceholder,
+    AlbumAssetCount,
+    PreparingAlbum,
+    AlbumFailed,
+    UploadFinishedWithFailures,
+    UploadSummary,
+    FailedFiles,
+    UploadedFiles,
+    RetryFailed,
+    UploadProgress,
     SelectPhotosOrVideos,
     ServerUrl,
     Save,
@@ -66,14 +101,86 @@ pub enum TextKey {
     Delete,
     ManageYourSettings,
     SaveFailed,
+    NoSecretStore,
     ServerUrlSaved,
+    ServerProfiles,
+    ProfileName,
+    CustomHeaders,
+    CaCertificate,
+    AcceptInvalidCerts,
+    ProfileUserCount,
+    ProfileSaved,
+    ProfileDeleted,
+    ProfileInUse,
+    InvalidHeader,
+    TestConnection,
+    Test,
+    TestingConnection,
+    ConnectionOk,
+    ConnectionOkWithAccount,
+    StorageUnlimited,
+    ConnectionFailed,
+    InvalidServerUrl,
+    ErrorNetwork,
+    ErrorTimeout,
+    ErrorUnauthorized,
+    ErrorForbidden,
+    ErrorQuotaExceeded,
+    ErrorUnsupportedMedia,
+    ErrorServer,
+    ErrorRejected,
+    ErrorIo,
     ConcurrencySaved,
     InvalidConcurrency,
+    RetryPolicy,
+    MaxAttempts,
+    BaseDelayMs,
+    JitterMs,
+    RetryConfigSaved,
+    InvalidRetryConfig,
+    BandwidthLimit,
+    UnlimitedWindow,
+    InvalidBandwidthConfig,
+    BandwidthConfigSaved,
     LanguageSaved,
     PleaseFillCompleteInfo,
     UserAdded,
+    UserExists,
     DefaultUserChanged,
     UserDeleted,
+    WatchedFolders,
+    ChooseFolder,
+    Recursive,
+    IncludePatterns,
+    ExcludePatterns,
+    NoWatchedFolders,
+    WatchedFolderAdded,
+    WatchedFolderRemoved,
+    InvalidWatchedFolder,
+    AutoUploadQueued,
+    AutoUploadFinished,
+    AutoUploadError,
+    WatcherStartFailed,
+    UploadHistory,
+    SearchHistory,
+    AllOutcomes,
+    OutcomeUploaded,
+    OutcomeDuplicate,
+    OutcomeFailed,
+    NoHistory,
+    HistoryCount,
+    LoadHistoryFailed,
+    ReopenFailed,
+    Reopen,
+    ResumeQueuePrompt,
+    ResumeUpload,
+    DiscardQueue,
+    DiscardQueueFailed,
+    PauseUpload,
+    CancelUpload,
+    UploadPaused,
+    CancellingUpload,
+    UploadCancelled,
 }
 
 fn build_translations() -> HashMap<(Language, TextKey), &'static str> {
@@ -89,6 +196,50 @@ fn build_translations() -> HashMap<(Language, TextKey), &'static str> {
         "请在设置中添加账号",
     );
     m.insert((Language::Chinese, TextKey::SelectFiles), "选择文件");
+    m.insert((Language::Chinese, TextKey::SelectFolder), "选择文件夹");
+    m.insert(
+        (Language::Chinese, TextKey::SelectFolders),
+        "选择包含照片或视频的文件夹",
+    );
+    m.insert(
+        (Language::Chinese, TextKey::ScanningFolders),
+        "正在扫描文件夹...",
+    );
+    m.insert(
+        (Language::Chinese, TextKey::FolderScanResult),
+        "找到 {} 个文件，忽略 {} 个",
+    );
+    m.insert(
+        (Language::Chinese, TextKey::DropFilesHint),
+        "也可以将文件或文件夹拖放到这里",
+    );
+    m.insert(
+        (Language::Chinese, TextKey::FilesAdded),
+        "已添加 {} 个文件，忽略 {} 个",
+    );
+    m.insert((Language::Chinese, TextKey::SortFiles), "排序...");
+    m.insert((Language::Chinese, TextKey::SortByName), "按名称排序");
+    m.insert((Language::Chinese, TextKey::SortByDate), "按拍摄日期排序");
+    m.insert((Language::Chinese, TextKey::SortBySize), "按大小排序");
+    m.insert((Language::Chinese, TextKey::SortByType), "按类型排序");
+    m.insert((Language::Chinese, TextKey::SortingFiles), "正在排序...");
+    m.insert((Language::Chinese, TextKey::AllMediaTypes), "全部类型");
+    m.insert((Language::Chinese, TextKey::ImagesOnly), "仅图片");
+    m.insert((Language::Chinese, TextKey::VideosOnly), "仅视频");
+    m.insert(
+        (Language::Chinese, TextKey::SelectionTotals),
+        "共 {}，图片 {} 个，视频 {} 个",
+    );
+    m.insert(
+        (Language::Chinese, TextKey::ShowingFiles),
+        "显示 {} / {} 个文件",
+    );
+    m.insert((Language::Chinese, TextKey::RemoveShown), "移除显示的文件");
+    m.insert((Language::Chinese, TextKey::RemoveFile), "从列表中移除");
+    m.insert(
+        (Language::Chinese, TextKey::SidecarAttached),
+        "已附带 XMP 元数据文件，将一起上传",
+    );
     m.insert((Language::Chinese, TextKey::Clear), "清除");
     m.insert((Language::Chinese, TextKey::StartUpload), "开始上传");
     m.insert((Language::Chinese, TextKey::Uploading), "正在上传...");
@@ -102,6 +253,11 @@ fn build_translations() -> HashMap<(Language, TextKey), &'static str> {
         (Language::Chinese, TextKey::SwitchedToUser),
         "已切换至用户: {}",
     );
+    m.insert((Language::Chinese, TextKey::ServerProfile), "服务器");
+    m.insert(
+        (Language::Chinese, TextKey::SwitchedToProfile),
+        "已切换到服务器：{}",
+    );
     m.insert(
         (Language::Chinese, TextKey::FilesSelected),
         "已选择 {} 个文件",
@@ -111,6 +267,10 @@ fn build_translations() -> HashMap<(Language, TextKey), &'static str> {
         (Language::Chinese, TextKey::UserApiKeyNotFound),
         "未找到选定用户的 API Key",
     );
+    m.insert(
+        (Language::Chinese, TextKey::ApiKeyUnavailable),
+        "API Key 缺失或密钥存储未解锁，请解锁系统密钥环或在设置中重新输入：{}",
+    );
     m.insert(
         (Language::Chinese, TextKey::ConfigFileNotFound),
         "未找到配置文件",
@@ -131,6 +291,52 @@ fn build_translations() -> HashMap<(Language, TextKey), &'static str> {
         (Language::Chinese, TextKey::AllFilesUploaded),
         "成功上传全部 {} 个文件！",
     );
+    m.insert(
+        (Language::Chinese, TextKey::CheckingDuplicates),
+        "正在检查 {} 个文件是否已存在...",
+    );
+    m.insert(
+        (Language::Chinese, TextKey::UploadFinishedWithSkipped),
+        "成功上传 {} 个文件，跳过 {} 个重复文件",
+    );
+    m.insert(
+        (Language::Chinese, TextKey::ContinueOnError),
+        "出错时继续上传",
+    );
+    m.insert((Language::Chinese, TextKey::ForceUpload), "强制重新上传");
+    m.insert(
+        (Language::Chinese, TextKey::ForceUploadHint),
+        "忽略本地上传记录，仅对本次上传生效",
+    );
+    m.insert((Language::Chinese, TextKey::Album), "相册（可选）");
+    m.insert(
+        (Language::Chinese, TextKey::AlbumPlaceholder),
+        "选择或输入新相册名称",
+    );
+    m.insert((Language::Chinese, TextKey::AlbumAssetCount), "{} 个项目");
+    m.insert(
+        (Language::Chinese, TextKey::PreparingAlbum),
+        "正在准备相册：{}",
+    );
+    m.insert(
+        (Language::Chinese, TextKey::AlbumFailed),
+        "无法准备相册：{}",
+    );
+    m.insert(
+        (Language::Chinese, TextKey::UploadFinishedWithFailures),
+        "上传完成：成功 {} 个，失败 {} 个",
+    );
+    m.insert(
+        (Language::Chinese, TextKey::UploadSummary),
+        "上传结果：成功 {}，跳过 {}，失败 {}",
+    );
+    m.insert((Language::Chinese, TextKey::FailedFiles), "失败的文件");
+    m.insert((Language::Chinese, TextKey::UploadedFiles), "已上传的文件");
+    m.insert((Language::Chinese, TextKey::RetryFailed), "重试失败项");
+    m.insert(
+        (Language::Chinese, TextKey::UploadProgress),
+        "{} / {} · {}/s · 剩余 {}",
+    );
     m.insert(
         (Language::Chinese, TextKey::SelectPhotosOrVideos),
         "选择照片或视频",
@@ -150,10 +356,104 @@ fn build_translations() -> HashMap<(Language, TextKey), &'static str> {
         "管理您的设置",
     );
     m.insert((Language::Chinese, TextKey::SaveFailed), "保存失败: {}");
+    m.insert(
+        (Language::Chinese, TextKey::NoSecretStore),
+        "没有可用的系统密钥环，无法保存新的 API Key。请设置环境变量 {} 作为加密口令后重新启动应用",
+    );
     m.insert(
         (Language::Chinese, TextKey::ServerUrlSaved),
         "服务器URL已保存",
     );
+    m.insert((Language::Chinese, TextKey::ServerProfiles), "服务器配置");
+    m.insert((Language::Chinese, TextKey::ProfileName), "配置名称");
+    m.insert(
+        (Language::Chinese, TextKey::CustomHeaders),
+        "自定义请求头（可选），每行一个，例如 CF-Access-Client-Id: xxx",
+    );
+    m.insert((Language::Chinese, TextKey::CaCertificate), "CA 证书");
+    m.insert(
+        (Language::Chinese, TextKey::AcceptInvalidCerts),
+        "接受无效证书",
+    );
+    m.insert((Language::Chinese, TextKey::ProfileUserCount), "{} 个用户");
+    m.insert(
+        (Language::Chinese, TextKey::ProfileSaved),
+        "服务器配置已保存：{}",
+    );
+    m.insert(
+        (Language::Chinese, TextKey::ProfileDeleted),
+        "服务器配置已删除：{}",
+    );
+    m.insert(
+        (Language::Chinese, TextKey::ProfileInUse),
+        "请先删除服务器配置 {} 下的用户",
+    );
+    m.insert(
+        (Language::Chinese, TextKey::InvalidHeader),
+        "无效的请求头：{}",
+    );
+    m.insert((Language::Chinese, TextKey::TestConnection), "测试连接");
+    m.insert((Language::Chinese, TextKey::Test), "测试");
+    m.insert(
+        (Language::Chinese, TextKey::TestingConnection),
+        "正在测试连接...",
+    );
+    m.insert(
+        (Language::Chinese, TextKey::ConnectionOk),
+        "连接成功：Immich {}",
+    );
+    m.insert(
+        (Language::Chinese, TextKey::ConnectionOkWithAccount),
+        "连接成功：Immich {}，账户 {} <{}>，存储 {}",
+    );
+    m.insert(
+        (Language::Chinese, TextKey::StorageUnlimited),
+        "已用 {}，无配额限制",
+    );
+    m.insert(
+        (Language::Chinese, TextKey::ConnectionFailed),
+        "连接失败：{}",
+    );
+    m.insert(
+        (Language::Chinese, TextKey::InvalidServerUrl),
+        "无效的服务器地址：{}",
+    );
+    m.insert(
+        (Language::Chinese, TextKey::ErrorNetwork),
+        "无法连接服务器：{}。请检查服务器地址和网络连接",
+    );
+    m.insert(
+        (Language::Chinese, TextKey::ErrorTimeout),
+        "服务器响应超时，请检查网络或降低并发上传数",
+    );
+    m.insert(
+        (Language::Chinese, TextKey::ErrorUnauthorized),
+        "API Key 无效或已被删除，请在设置中更新 API Key",
+    );
+    m.insert(
+        (Language::Chinese, TextKey::ErrorForbidden),
+        "没有上传权限：{}。请确认 API Key 包含 asset.upload 权限",
+    );
+    m.insert(
+        (Language::Chinese, TextKey::ErrorQuotaExceeded),
+        "存储空间已超出配额，请清理空间或联系管理员提高配额",
+    );
+    m.insert(
+        (Language::Chinese, TextKey::ErrorUnsupportedMedia),
+        "服务器不支持此文件类型：{}",
+    );
+    m.insert(
+        (Language::Chinese, TextKey::ErrorServer),
+        "服务器错误 ({})：{}。请稍后重试",
+    );
+    m.insert(
+        (Language::Chinese, TextKey::ErrorRejected),
+        "服务器拒绝了此文件 ({})：{}",
+    );
+    m.insert(
+        (Language::Chinese, TextKey::ErrorIo),
+        "无法读取文件：{}。请确认文件存在且有读取权限",
+    );
     m.insert(
         (Language::Chinese, TextKey::ConcurrencySaved),
         "并发设置已保存",
@@ -162,6 +462,34 @@ fn build_translations() -> HashMap<(Language, TextKey), &'static str> {
         (Language::Chinese, TextKey::InvalidConcurrency),
         "并发数必须在 1-16 之间",
     );
+    m.insert((Language::Chinese, TextKey::RetryPolicy), "重试策略");
+    m.insert((Language::Chinese, TextKey::MaxAttempts), "最大尝试次数");
+    m.insert((Language::Chinese, TextKey::BaseDelayMs), "初始等待 (毫秒)");
+    m.insert((Language::Chinese, TextKey::JitterMs), "随机抖动 (毫秒)");
+    m.insert(
+        (Language::Chinese, TextKey::RetryConfigSaved),
+        "重试策略已保存",
+    );
+    m.insert(
+        (Language::Chinese, TextKey::InvalidRetryConfig),
+        "尝试次数必须在 1-10 之间，初始等待必须在 100-60000 毫秒之间",
+    );
+    m.insert(
+        (Language::Chinese, TextKey::BandwidthLimit),
+        "上传限速 (KB/s，0 为不限速)",
+    );
+    m.insert(
+        (Language::Chinese, TextKey::UnlimitedWindow),
+        "以下时间段不限速",
+    );
+    m.insert(
+        (Language::Chinese, TextKey::InvalidBandwidthConfig),
+        "无效的限速设置：不限速时段的开始和结束时间不能相同",
+    );
+    m.insert(
+        (Language::Chinese, TextKey::BandwidthConfigSaved),
+        "上传限速已保存",
+    );
     m.insert(
         (Language::Chinese, TextKey::LanguageSaved),
         "语言设置已保存",
@@ -171,11 +499,99 @@ fn build_translations() -> HashMap<(Language, TextKey), &'static str> {
         "请填写完整信息",
     );
     m.insert((Language::Chinese, TextKey::UserAdded), "用户已添加");
+    m.insert(
+        (Language::Chinese, TextKey::UserExists),
+        "用户 {} 已存在（服务器配置 {}），请使用其他名称",
+    );
     m.insert(
         (Language::Chinese, TextKey::DefaultUserChanged),
         "默认用户已更改为: {}",
     );
     m.insert((Language::Chinese, TextKey::UserDeleted), "用户 {} 已删除");
+    m.insert((Language::Chinese, TextKey::WatchedFolders), "监控文件夹");
+    m.insert((Language::Chinese, TextKey::ChooseFolder), "选择文件夹");
+    m.insert((Language::Chinese, TextKey::Recursive), "包含子目录");
+    m.insert(
+        (Language::Chinese, TextKey::IncludePatterns),
+        "包含 (例如 *.jpg, DCIM/**)",
+    );
+    m.insert(
+        (Language::Chinese, TextKey::ExcludePatterns),
+        "排除 (例如 *.tmp)",
+    );
+    m.insert(
+        (Language::Chinese, TextKey::NoWatchedFolders),
+        "暂无监控文件夹",
+    );
+    m.insert(
+        (Language::Chinese, TextKey::WatchedFolderAdded),
+        "监控文件夹已添加",
+    );
+    m.insert(
+        (Language::Chinese, TextKey::WatchedFolderRemoved),
+        "监控文件夹已移除",
+    );
+    m.insert(
+        (Language::Chinese, TextKey::InvalidWatchedFolder),
+        "无效的监控文件夹: {}",
+    );
+    m.insert(
+        (Language::Chinese, TextKey::AutoUploadQueued),
+        "自动上传: 发现 {} 个新文件，用户 {}",
+    );
+    m.insert(
+        (Language::Chinese, TextKey::AutoUploadFinished),
+        "自动上传完成: 成功 {}，跳过 {}，失败 {}",
+    );
+    m.insert(
+        (Language::Chinese, TextKey::AutoUploadError),
+        "{} 的自动上传失败: {}",
+    );
+    m.insert(
+        (Language::Chinese, TextKey::WatcherStartFailed),
+        "文件夹监控启动失败: {}",
+    );
+    m.insert((Language::Chinese, TextKey::UploadHistory), "上传历史");
+    m.insert((Language::Chinese, TextKey::SearchHistory), "搜索文件路径");
+    m.insert((Language::Chinese, TextKey::AllOutcomes), "全部状态");
+    m.insert((Language::Chinese, TextKey::OutcomeUploaded), "已上传");
+    m.insert((Language::Chinese, TextKey::OutcomeDuplicate), "已存在");
+    m.insert((Language::Chinese, TextKey::OutcomeFailed), "失败");
+    m.insert((Language::Chinese, TextKey::NoHistory), "暂无上传记录");
+    m.insert((Language::Chinese, TextKey::HistoryCount), "共 {} 条记录");
+    m.insert(
+        (Language::Chinese, TextKey::LoadHistoryFailed),
+        "无法读取上传历史：{}",
+    );
+    m.insert(
+        (Language::Chinese, TextKey::ReopenFailed),
+        "重新选择失败项 ({})",
+    );
+    m.insert((Language::Chinese, TextKey::Reopen), "重新选择");
+    m.insert(
+        (Language::Chinese, TextKey::ResumeQueuePrompt),
+        "上次有 {} 个文件未上传完成（账户：{}），是否继续？",
+    );
+    m.insert((Language::Chinese, TextKey::ResumeUpload), "继续上传");
+    m.insert((Language::Chinese, TextKey::DiscardQueue), "放弃");
+    m.insert(
+        (Language::Chinese, TextKey::DiscardQueueFailed),
+        "无法删除上传队列：{}",
+    );
+    m.insert((Language::Chinese, TextKey::PauseUpload), "暂停");
+    m.insert((Language::Chinese, TextKey::CancelUpload), "取消");
+    m.insert(
+        (Language::Chinese, TextKey::UploadPaused),
+        "已暂停，正在上传的文件完成后将不再开始新的文件",
+    );
+    m.insert(
+        (Language::Chinese, TextKey::CancellingUpload),
+        "正在取消...",
+    );
+    m.insert(
+        (Language::Chinese, TextKey::UploadCancelled),
+        "上传已取消：成功 {} 个，{} 个文件未上传",
+    );
     m.insert(
         (Language::English, TextKey::HomeHeader),
         "Upload Photos & Videos",
@@ -190,6 +606,53 @@ fn build_translations() -> HashMap<(Language, TextKey), &'static str> {
         "Please add account in settings",
     );
     m.insert((Language::English, TextKey::SelectFiles), "Select Files");
+    m.insert((Language::English, TextKey::SelectFolder), "Select Folder");
+    m.insert(
+        (Language::English, TextKey::SelectFolders),
+        "Select folders with photos or videos",
+    );
+    m.insert(
+        (Language::English, TextKey::ScanningFolders),
+        "Scanning folders...",
+    );
+    m.insert(
+        (Language::English, TextKey::FolderScanResult),
+        "Found {} files, ignored {}",
+    );
+    m.insert(
+        (Language::English, TextKey::DropFilesHint),
+        "Or drag and drop files and folders here",
+    );
+    m.insert(
+        (Language::English, TextKey::FilesAdded),
+        "Added {} files, ignored {}",
+    );
+    m.insert((Language::English, TextKey::SortFiles), "Sort...");
+    m.insert((Language::English, TextKey::SortByName), "Sort by name");
+    m.insert((Language::English, TextKey::SortByDate), "Sort by date");
+    m.insert((Language::English, TextKey::SortBySize), "Sort by size");
+    m.insert((Language::English, TextKey::SortByType), "Sort by type");
+    m.insert((Language::English, TextKey::SortingFiles), "Sorting...");
+    m.insert((Language::English, TextKey::AllMediaTypes), "All types");
+    m.insert((Language::English, TextKey::ImagesOnly), "Images");
+    m.insert((Language::English, TextKey::VideosOnly), "Videos");
+    m.insert(
+        (Language::English, TextKey::SelectionTotals),
+        "{} in total, {} images, {} videos",
+    );
+    m.insert(
+        (Language::English, TextKey::ShowingFiles),
+        "Showing {} of {} files",
+    );
+    m.insert((Language::English, TextKey::RemoveShown), "Remove shown");
+    m.insert(
+        (Language::English, TextKey::RemoveFile),
+        "Remove from selection",
+    );
+    m.insert(
+        (Language::English, TextKey::SidecarAttached),
+        "XMP sidecar attached, uploaded with this file",
+    );
     m.insert((Language::English, TextKey::Clear), "Clear");
     m.insert((Language::English, TextKey::StartUpload), "Start Upload");
     m.insert((Language::English, TextKey::Uploading), "Uploading...");
@@ -206,6 +669,11 @@ fn build_translations() -> HashMap<(Language, TextKey), &'static str> {
         (Language::English, TextKey::SwitchedToUser),
         "Switched to user: {}",
     );
+    m.insert((Language::English, TextKey::ServerProfile), "Server");
+    m.insert(
+        (Language::English, TextKey::SwitchedToProfile),
+        "Switched to server: {}",
+    );
     m.insert(
         (Language::English, TextKey::FilesSelected),
         "{} files selected",
@@ -218,6 +686,10 @@ fn build_translations() -> HashMap<(Language, TextKey), &'static str> {
         (Language::English, TextKey::UserApiKeyNotFound),
         "API Key not found for selected user",
     );
+    m.insert(
+        (Language::English, TextKey::ApiKeyUnavailable),
+        "API Key is missing or the secret store is locked. Unlock the keyring or re-enter the key in Settings: {}",
+    );
     m.insert(
         (Language::English, TextKey::ConfigFileNotFound),
         "Configuration file not found",
@@ -238,6 +710,55 @@ fn build_translations() -> HashMap<(Language, TextKey), &'static str> {
         (Language::English, TextKey::AllFilesUploaded),
         "Successfully uploaded all {} files!",
     );
+    m.insert(
+        (Language::English, TextKey::CheckingDuplicates),
+        "Checking {} files for duplicates...",
+    );
+    m.insert(
+        (Language::English, TextKey::UploadFinishedWithSkipped),
+        "Uploaded {} files, skipped {} duplicates",
+    );
+    m.insert(
+        (Language::English, TextKey::ContinueOnError),
+        "Continue on error",
+    );
+    m.insert((Language::English, TextKey::ForceUpload), "Force re-upload");
+    m.insert(
+        (Language::English, TextKey::ForceUploadHint),
+        "Ignore the local upload record for this batch only",
+    );
+    m.insert((Language::English, TextKey::Album), "Album (optional)");
+    m.insert(
+        (Language::English, TextKey::AlbumPlaceholder),
+        "Choose an album or type a new name",
+    );
+    m.insert((Language::English, TextKey::AlbumAssetCount), "{} items");
+    m.insert(
+        (Language::English, TextKey::PreparingAlbum),
+        "Preparing album: {}",
+    );
+    m.insert(
+        (Language::English, TextKey::AlbumFailed),
+        "Failed to prepare album: {}",
+    );
+    m.insert(
+        (Language::English, TextKey::UploadFinishedWithFailures),
+        "Upload finished: {} succeeded, {} failed",
+    );
+    m.insert(
+        (Language::English, TextKey::UploadSummary),
+        "Upload summary: {} uploaded, {} skipped, {} failed",
+    );
+    m.insert((Language::English, TextKey::FailedFiles), "Failed files");
+    m.insert(
+        (Language::English, TextKey::UploadedFiles),
+        "Uploaded files",
+    );
+    m.insert((Language::English, TextKey::RetryFailed), "Retry Failed");
+    m.insert(
+        (Language::English, TextKey::UploadProgress),
+        "{} / {} · {}/s · ETA {}",
+    );
     m.insert(
         (Language::English, TextKey::SelectPhotosOrVideos),
         "Select photos or videos",
@@ -260,10 +781,113 @@ fn build_translations() -> HashMap<(Language, TextKey), &'static str> {
         "Manage your settings",
     );
     m.insert((Language::English, TextKey::SaveFailed), "Save failed: {}");
+    m.insert(
+        (Language::English, TextKey::NoSecretStore),
+        "No system keyring is available, so new API keys cannot be saved. Set the {} environment variable to an encryption passphrase and restart the app",
+    );
     m.insert(
         (Language::English, TextKey::ServerUrlSaved),
         "Server URL saved",
     );
+    m.insert(
+        (Language::English, TextKey::ServerProfiles),
+        "Server Profiles",
+    );
+    m.insert((Language::English, TextKey::ProfileName), "Profile name");
+    m.insert(
+        (Language::English, TextKey::CustomHeaders),
+        "Custom headers (optional), one per line, e.g. CF-Access-Client-Id: xxx",
+    );
+    m.insert(
+        (Language::English, TextKey::CaCertificate),
+        "CA Certificate",
+    );
+    m.insert(
+        (Language::English, TextKey::AcceptInvalidCerts),
+        "Accept invalid certificates",
+    );
+    m.insert((Language::English, TextKey::ProfileUserCount), "{} users");
+    m.insert(
+        (Language::English, TextKey::ProfileSaved),
+        "Server profile saved: {}",
+    );
+    m.insert(
+        (Language::English, TextKey::ProfileDeleted),
+        "Server profile deleted: {}",
+    );
+    m.insert(
+        (Language::English, TextKey::ProfileInUse),
+        "Remove the users of server profile {} first",
+    );
+    m.insert(
+        (Language::English, TextKey::InvalidHeader),
+        "Invalid header: {}",
+    );
+    m.insert(
+        (Language::English, TextKey::TestConnection),
+        "Test Connection",
+    );
+    m.insert((Language::English, TextKey::Test), "Test");
+    m.insert(
+        (Language::English, TextKey::TestingConnection),
+        "Testing connection...",
+    );
+    m.insert(
+        (Language::English, TextKey::ConnectionOk),
+        "Connected: Immich {}",
+    );
+    m.insert(
+        (Language::English, TextKey::ConnectionOkWithAccount),
+        "Connected: Immich {}, account {} <{}>, storage {}",
+    );
+    m.insert(
+        (Language::English, TextKey::StorageUnlimited),
+        "{} used, no quota",
+    );
+    m.insert(
+        (Language::English, TextKey::ConnectionFailed),
+        "Connection failed: {}",
+    );
+    m.insert(
+        (Language::English, TextKey::InvalidServerUrl),
+        "Invalid server URL: {}",
+    );
+    m.insert(
+        (Language::English, TextKey::ErrorNetwork),
+        "Cannot reach the server: {}. Check the server address and your network connection",
+    );
+    m.insert(
+        (Language::English, TextKey::ErrorTimeout),
+        "The server did not respond in time. Check your network or lower the upload concurrency",
+    );
+    m.insert(
+        (Language::English, TextKey::ErrorUnauthorized),
+        "The API Key is invalid or has been deleted. Update it in Settings",
+    );
+    m.insert(
+        (Language::English, TextKey::ErrorForbidden),
+        "Upload not allowed: {}. Make sure the API Key has the asset.upload permission",
+    );
+    m.insert(
+        (Language::English, TextKey::ErrorQuotaExceeded),
+        "Storage quota exceeded. Free up space or ask the administrator to raise the quota",
+    );
+    m.insert(
+        (Language::English, TextKey::ErrorUnsupportedMedia),
+        "The server does not support this file type: {}",
+    );
+    m.insert(
+        (Language::English, TextKey::ErrorServer),
+        "Server error ({}): {}. Please try again later",
+    );
+    m.insert(
+        (Language::English, TextKey::ErrorRejected),
+        "The server rejected this file ({}): {}",
+    );
+    m.insert(
+        (Language::English, TextKey::ErrorIo),
+        "Cannot read the file: {}. Make sure it exists and is readable",
+    );
     m.insert(
         (Language::English, TextKey::ConcurrencySaved),
         "Concurrency settings saved",
@@ -272,6 +896,34 @@ fn build_translations() -> HashMap<(Language, TextKey), &'static str> {
         (Language::English, TextKey::InvalidConcurrency),
         "Concurrency must be between 1 and 16",
     );
+    m.insert((Language::English, TextKey::RetryPolicy), "Retry Policy");
+    m.insert((Language::English, TextKey::MaxAttempts), "Max attempts");
+    m.insert((Language::English, TextKey::BaseDelayMs), "Base delay (ms)");
+    m.insert((Language::English, TextKey::JitterMs), "Jitter (ms)");
+    m.insert(
+        (Language::English, TextKey::RetryConfigSaved),
+        "Retry policy saved",
+    );
+    m.insert(
+        (Language::English, TextKey::InvalidRetryConfig),
+        "Max attempts must be between 1 and 10, base delay between 100 and 60000 ms",
+    );
+    m.insert(
+        (Language::English, TextKey::BandwidthLimit),
+        "Upload Limit (KB/s, 0 = Unlimited)",
+    );
+    m.insert(
+        (Language::English, TextKey::UnlimitedWindow),
+        "Unlimited between",
+    );
+    m.insert(
+        (Language::English, TextKey::InvalidBandwidthConfig),
+        "Invalid limit: the unlimited period must have different start and end times",
+    );
+    m.insert(
+        (Language::English, TextKey::BandwidthConfigSaved),
+        "Upload limit saved",
+    );
     m.insert(
         (Language::English, TextKey::LanguageSaved),
         "Language settings saved",
@@ -281,11 +933,114 @@ fn build_translations() -> HashMap<(Language, TextKey), &'static str> {
         "Please fill in complete information",
     );
     m.insert((Language::English, TextKey::UserAdded), "User added");
+    m.insert(
+        (Language::English, TextKey::UserExists),
+        "User {} already exists (profile {}), choose another name",
+    );
     m.insert(
         (Language::English, TextKey::DefaultUserChanged),
         "Default user changed to: {}",
     );
     m.insert((Language::English, TextKey::UserDeleted), "User {} deleted");
+    m.insert(
+        (Language::English, TextKey::WatchedFolders),
+        "Watched Folders",
+    );
+    m.insert((Language::English, TextKey::ChooseFolder), "Choose Folder");
+    m.insert((Language::English, TextKey::Recursive), "Recursive");
+    m.insert(
+        (Language::English, TextKey::IncludePatterns),
+        "Include (e.g. *.jpg, DCIM/**)",
+    );
+    m.insert(
+        (Language::English, TextKey::ExcludePatterns),
+        "Exclude (e.g. *.tmp)",
+    );
+    m.insert(
+        (Language::English, TextKey::NoWatchedFolders),
+        "No watched folders",
+    );
+    m.insert(
+        (Language::English, TextKey::WatchedFolderAdded),
+        "Watched folder added",
+    );
+    m.insert(
+        (Language::English, TextKey::WatchedFolderRemoved),
+        "Watched folder removed",
+    );
+    m.insert(
+        (Language::English, TextKey::InvalidWatchedFolder),
+        "Invalid watched folder: {}",
+    );
+    m.insert(
+        (Language::English, TextKey::AutoUploadQueued),
+        "Auto upload: {} new files for {}",
+    );
+    m.insert(
+        (Language::English, TextKey::AutoUploadFinished),
+        "Auto upload finished: {} uploaded, {} skipped, {} failed",
+    );
+    m.insert(
+        (Language::English, TextKey::AutoUploadError),
+        "Auto upload failed for {}: {}",
+    );
+    m.insert(
+        (Language::English, TextKey::WatcherStartFailed),
+        "Failed to start folder watcher: {}",
+    );
+    m.insert(
+        (Language::English, TextKey::UploadHistory),
+        "Upload History",
+    );
+    m.insert(
+        (Language::English, TextKey::SearchHistory),
+        "Search by file path",
+    );
+    m.insert((Language::English, TextKey::AllOutcomes), "All statuses");
+    m.insert((Language::English, TextKey::OutcomeUploaded), "Uploaded");
+    m.insert(
+        (Language::English, TextKey::OutcomeDuplicate),
+        "Already on server",
+    );
+    m.insert((Language::English, TextKey::OutcomeFailed), "Failed");
+    m.insert(
+        (Language::English, TextKey::NoHistory),
+        "No uploads recorded yet",
+    );
+    m.insert((Language::English, TextKey::HistoryCount), "{} entries");
+    m.insert(
+        (Language::English, TextKey::LoadHistoryFailed),
+        "Failed to load upload history: {}",
+    );
+    m.insert(
+        (Language::English, TextKey::ReopenFailed),
+        "Re-open failed items ({})",
+    );
+    m.insert((Language::English, TextKey::Reopen), "Re-open");
+    m.insert(
+        (Language::English, TextKey::ResumeQueuePrompt),
+        "{} files from the last upload (account {}) were not finished. Resume?",
+    );
+    m.insert((Language::English, TextKey::ResumeUpload), "Resume");
+    m.insert((Language::English, TextKey::DiscardQueue), "Discard");
+    m.insert(
+        (Language::English, TextKey::DiscardQueueFailed),
+        "Failed to discard upload queue: {}",
+    );
+    m.insert((Language::English, TextKey::PauseUpload), "Pause");
+    m.insert((Language::English, TextKey::CancelUpload), "Cancel");
+    m.insert(
+        (Language::English, TextKey::UploadPaused),
+        "Paused. Files already uploading will finish, no new files will start",
+    );
+    m.insert(
+        (Language::English, TextKey::CancellingUpload),
+        "Cancelling...",
+    );
+    m.insert(
+        (Language::English, TextKey::UploadCancelled),
+        "Upload cancelled: {} uploaded, {} not uploaded",
+    );
     m
 }
 
@@ -325,6 +1080,75 @@ impl I18n {
     pub fn select_files(&self) -> &'static str {
         self.get(TextKey::SelectFiles)
     }
+    pub fn select_folder(&self) -> &'static str {
+        self.get(TextKey::SelectFolder)
+    }
+    pub fn select_folders(&self) -> &'static str {
+        self.get(TextKey::SelectFolders)
+    }
+    pub fn scanning_folders(&self) -> &'static str {
+        self.get(TextKey::ScanningFolders)
+    }
+    pub fn folder_scan_result(&self, found: usize, ignored: usize) -> String {
+        self.get(TextKey::FolderScanResult)
+            .replacen("{}", &found.to_string(), 1)
+            .replacen("{}", &ignored.to_string(), 1)
+    }
+    pub fn drop_files_hint(&self) -> &'static str {
+        self.get(TextKey::DropFilesHint)
+    }
+    pub fn files_added(&self, added: usize, ignored: usize) -> String {
+        self.get(TextKey::FilesAdded)
+            .replacen("{}", &added.to_string(), 1)
+            .replacen("{}", &ignored.to_string(), 1)
+    }
+    pub fn sort_files(&self) -> &'static str {
+        self.get(TextKey::SortFiles)
+    }
+    pub fn sort_by_name(&self) -> &'static str {
+        self.get(TextKey::SortByName)
+    }
+    pub fn sort_by_date(&self) -> &'static str {
+        self.get(TextKey::SortByDate)
+    }
+    pub fn sort_by_size(&self) -> &'static str {
+        self.get(TextKey::SortBySize)
+    }
+    pub fn sort_by_type(&self) -> &'static str {
+        self.get(TextKey::SortByType)
+    }
+    pub fn sorting_files(&self) -> &'static str {
+        self.get(TextKey::SortingFiles)
+    }
+    pub fn all_media_types(&self) -> &'static str {
+        self.get(TextKey::AllMediaTypes)
+    }
+    pub fn images_only(&self) -> &'static str {
+        self.get(TextKey::ImagesOnly)
+    }
+    pub fn videos_only(&self) -> &'static str {
+        self.get(TextKey::VideosOnly)
+    }
+    pub fn selection_totals(&self, size: &str, images: usize, videos: usize) -> String {
+        self.get(TextKey::SelectionTotals)
+            .replacen("{}", size, 1)
+            .replacen("{}", &images.to_string(), 1)
+            .replacen("{}", &videos.to_string(), 1)
+    }
+    pub fn showing_files(&self, shown: usize, total: usize) -> String {
+        self.get(TextKey::ShowingFiles)
+            .replacen("{}", &shown.to_string(), 1)
+            .replacen("{}", &total.to_string(), 1)
+    }
+    pub fn remove_shown(&self) -> &'static str {
+        self.get(TextKey::RemoveShown)
+    }
+    pub fn remove_file(&self) -> &'static str {
+        self.get(TextKey::RemoveFile)
+    }
+    pub fn sidecar_attached(&self) -> &'static str {
+        self.get(TextKey::SidecarAttached)
+    }
     pub fn clear(&self) -> &'static str {
         self.get(TextKey::Clear)
     }
@@ -347,6 +1171,12 @@ impl I18n {
     pub fn switched_to_user(&self, username: &str) -> String {
         self.get(TextKey::SwitchedToUser).replace("{}", username)
     }
+    pub fn server_profile(&self) -> &'static str {
+        self.get(TextKey::ServerProfile)
+    }
+    pub fn switched_to_profile(&self, name: &str) -> String {
+        self.get(TextKey::SwitchedToProfile).replace("{}", name)
+    }
     pub fn files_selected(&self, count: usize) -> String {
         self.get(TextKey::FilesSelected)
             .replace("{}", &count.to_string())
@@ -357,6 +1187,9 @@ impl I18n {
     pub fn user_api_key_not_found(&self) -> &'static str {
         self.get(TextKey::UserApiKeyNotFound)
     }
+    pub fn api_key_unavailable(&self, error: &str) -> String {
+        self.get(TextKey::ApiKeyUnavailable).replace("{}", error)
+    }
     pub fn config_file_not_found(&self) -> &'static str {
         self.get(TextKey::ConfigFileNotFound)
     }
@@ -379,6 +1212,67 @@ impl I18n {
         self.get(TextKey::AllFilesUploaded)
             .replace("{}", &total.to_string())
     }
+    pub fn checking_duplicates(&self, total: usize) -> String {
+        self.get(TextKey::CheckingDuplicates)
+            .replace("{}", &total.to_string())
+    }
+    pub fn upload_finished_with_skipped(&self, uploaded: usize, skipped: usize) -> String {
+        self.get(TextKey::UploadFinishedWithSkipped)
+            .replacen("{}", &uploaded.to_string(), 1)
+            .replacen("{}", &skipped.to_string(), 1)
+    }
+    pub fn continue_on_error(&self) -> &'static str {
+        self.get(TextKey::ContinueOnError)
+    }
+    pub fn force_upload(&self) -> &'static str {
+        self.get(TextKey::ForceUpload)
+    }
+    pub fn force_upload_hint(&self) -> &'static str {
+        self.get(TextKey::ForceUploadHint)
+    }
+    pub fn album(&self) -> &'static str {
+        self.get(TextKey::Album)
+    }
+    pub fn album_placeholder(&self) -> &'static str {
+        self.get(TextKey::AlbumPlaceholder)
+    }
+    pub fn album_asset_count(&self, count: usize) -> String {
+        self.get(TextKey::AlbumAssetCount)
+            .replace("{}", &count.to_string())
+    }
+    pub fn preparing_album(&self, name: &str) -> String {
+        self.get(TextKey::PreparingAlbum).replace("{}", name)
+    }
+    pub fn album_failed(&self, error: &str) -> String {
+        self.get(TextKey::AlbumFailed).replace("{}", error)
+    }
+    pub fn upload_finished_with_failures(&self, uploaded: usize, failed: usize) -> String {
+        self.get(TextKey::UploadFinishedWithFailures)
+            .replacen("{}", &uploaded.to_string(), 1)
+            .replacen("{}", &failed.to_string(), 1)
+    }
+    pub fn upload_summary(&self, uploaded: usize, skipped: usize, failed: usize) -> String {
+        self.get(TextKey::UploadSummary)
+            .replacen("{}", &uploaded.to_string(), 1)
+            .replacen("{}", &skipped.to_string(), 1)
+            .replacen("{}", &failed.to_string(), 1)
+    }
+    pub fn failed_files(&self) -> &'static str {
+        self.get(TextKey::FailedFiles)
+    }
+    pub fn uploaded_files(&self) -> &'static str {
+        self.get(TextKey::UploadedFiles)
+    }
+    pub fn retry_failed(&self) -> &'static str {
+        self.get(TextKey::RetryFailed)
+    }
+    pub fn upload_progress(&self, sent: &str, total: &str, speed: &str, eta: &str) -> String {
+        self.get(TextKey::UploadProgress)
+            .replacen("{}", sent, 1)
+            .replacen("{}", total, 1)
+            .replacen("{}", speed, 1)
+            .replacen("{}", eta, 1)
+    }
     pub fn select_photos_or_videos(&self) -> &'static str {
         self.get(TextKey::SelectPhotosOrVideos)
     }
@@ -418,15 +1312,160 @@ impl I18n {
     pub fn save_failed(&self, error: &str) -> String {
         self.get(TextKey::SaveFailed).replace("{}", error)
     }
+    pub fn no_secret_store(&self, env: &str) -> String {
+        self.get(TextKey::NoSecretStore).replace("{}", env)
+    }
     pub fn server_url_saved(&self) -> &'static str {
         self.get(TextKey::ServerUrlSaved)
     }
+    pub fn server_profiles(&self) -> &'static str {
+        self.get(TextKey::ServerProfiles)
+    }
+    pub fn profile_name(&self) -> &'static str {
+        self.get(TextKey::ProfileName)
+    }
+    pub fn custom_headers(&self) -> &'static str {
+        self.get(TextKey::CustomHeaders)
+    }
+    pub fn ca_certificate(&self) -> &'static str {
+        self.get(TextKey::CaCertificate)
+    }
+    pub fn accept_invalid_certs(&self) -> &'static str {
+        self.get(TextKey::AcceptInvalidCerts)
+    }
+    pub fn profile_user_count(&self, count: usize) -> String {
+        self.get(TextKey::ProfileUserCount)
+            .replace("{}", &count.to_string())
+    }
+    pub fn profile_saved(&self, name: &str) -> String {
+        self.get(TextKey::ProfileSaved).replace("{}", name)
+    }
+    pub fn profile_deleted(&self, name: &str) -> String {
+        self.get(TextKey::ProfileDeleted).replace("{}", name)
+    }
+    pub fn profile_in_use(&self, name: &str) -> String {
+        self.get(TextKey::ProfileInUse).replace("{}", name)
+    }
+    pub fn invalid_header(&self, line: &str) -> String {
+        self.get(TextKey::InvalidHeader).replace("{}", line)
+    }
+    pub fn test_connection(&self) -> &'static str {
+        self.get(TextKey::TestConnection)
+    }
+    pub fn test(&self) -> &'static str {
+        self.get(TextKey::Test)
+    }
+    pub fn testing_connection(&self) -> &'static str {
+        self.get(TextKey::TestingConnection)
+    }
+    pub fn connection_ok(&self, version: &str) -> String {
+        self.get(TextKey::ConnectionOk).replace("{}", version)
+    }
+    pub fn connection_ok_with_account(
+        &self,
+        version: &str,
+        name: &str,
+        email: &str,
+        storage: &str,
+    ) -> String {
+        self.get(TextKey::ConnectionOkWithAccount)
+            .replacen("{}", version, 1)
+            .replacen("{}", name, 1)
+            .replacen("{}", email, 1)
+            .replacen("{}", storage, 1)
+    }
+    pub fn storage_unlimited(&self, used: &str) -> String {
+        self.get(TextKey::StorageUnlimited).replace("{}", used)
+    }
+    pub fn connection_failed(&self, error: &str) -> String {
+        self.get(TextKey::ConnectionFailed).replace("{}", error)
+    }
+    pub fn invalid_server_url(&self, error: &str) -> String {
+        self.get(TextKey::InvalidServerUrl).replace("{}", error)
+    }
+    pub fn error_network(&self, detail: &str) -> String {
+        self.get(TextKey::ErrorNetwork).replace("{}", detail)
+    }
+    pub fn error_timeout(&self) -> &'static str {
+        self.get(TextKey::ErrorTimeout)
+    }
+    pub fn error_unauthorized(&self) -> &'static str {
+        self.get(TextKey::ErrorUnauthorized)
+    }
+    pub fn error_forbidden(&self, detail: &str) -> String {
+        self.get(TextKey::ErrorForbidden).replace("{}", detail)
+    }
+    pub fn error_quota_exceeded(&self) -> &'static str {
+        self.get(TextKey::ErrorQuotaExceeded)
+    }
+    pub fn error_unsupported_media(&self, detail: &str) -> String {
+        self.get(TextKey::ErrorUnsupportedMedia)
+            .replace("{}", detail)
+    }
+    pub fn error_server(&self, status: u16, detail: &str) -> String {
+        self.get(TextKey::ErrorServer)
+            .replacen("{}", &status.to_string(), 1)
+            .replacen("{}", detail, 1)
+    }
+    pub fn error_rejected(&self, status: u16, detail: &str) -> String {
+        self.get(TextKey::ErrorRejected)
+            .replacen("{}", &status.to_string(), 1)
+            .replacen("{}", detail, 1)
+    }
+    pub fn error_io(&self, detail: &str) -> String {
+        self.get(TextKey::ErrorIo).replace("{}", detail)
+    }
+    /// 上传错误的本地化提示
+    pub fn upload_error(&self, error: &UploadError) -> String {
+        match error {
+            UploadError::Network(detail) => self.error_network(detail),
+            UploadError::Timeout => self.error_timeout().to_string(),
+            UploadError::Unauthorized(_) => self.error_unauthorized().to_string(),
+            UploadError::Forbidden(detail) => self.error_forbidden(detail),
+            UploadError::QuotaExceeded(_) => self.error_quota_exceeded().to_string(),
+            UploadError::UnsupportedMedia(detail) => self.error_unsupported_media(detail),
+            UploadError::Server { status, message } => self.error_server(*status, message),
+            UploadError::Rejected { status, message } => self.error_rejected(*status, message),
+            UploadError::Io(detail) => self.error_io(detail),
+            UploadError::Other(detail) => detail.clone(),
+        }
+    }
     pub fn concurrency_saved(&self) -> &'static str {
         self.get(TextKey::ConcurrencySaved)
     }
     pub fn invalid_concurrency(&self) -> &'static str {
         self.get(TextKey::InvalidConcurrency)
     }
+    pub fn retry_policy(&self) -> &'static str {
+        self.get(TextKey::RetryPolicy)
+    }
+    pub fn max_attempts(&self) -> &'static str {
+        self.get(TextKey::MaxAttempts)
+    }
+    pub fn base_delay_ms(&self) -> &'static str {
+        self.get(TextKey::BaseDelayMs)
+    }
+    pub fn jitter_ms(&self) -> &'static str {
+        self.get(TextKey::JitterMs)
+    }
+    pub fn retry_config_saved(&self) -> &'static str {
+        self.get(TextKey::RetryConfigSaved)
+    }
+    pub fn invalid_retry_config(&self) -> &'static str {
+        self.get(TextKey::InvalidRetryConfig)
+    }
+    pub fn bandwidth_limit(&self) -> &'static str {
+        self.get(TextKey::BandwidthLimit)
+    }
+    pub fn unlimited_window(&self) -> &'static str {
+        self.get(TextKey::UnlimitedWindow)
+    }
+    pub fn invalid_bandwidth_config(&self) -> &'static str {
+        self.get(TextKey::InvalidBandwidthConfig)
+    }
+    pub fn bandwidth_config_saved(&self) -> &'static str {
+        self.get(TextKey::BandwidthConfigSaved)
+    }
     pub fn language_saved(&self) -> &'static str {
         self.get(TextKey::LanguageSaved)
     }
@@ -436,6 +1475,11 @@ impl I18n {
     pub fn user_added(&self) -> &'static str {
         self.get(TextKey::UserAdded)
     }
+    pub fn user_exists(&self, username: &str, profile: &str) -> String {
+        self.get(TextKey::UserExists)
+            .replacen("{}", username, 1)
+            .replacen("{}", profile, 1)
+    }
     pub fn default_user_changed(&self, username: &str) -> String {
         self.get(TextKey::DefaultUserChanged)
             .replace("{}", username)
@@ -443,4 +1487,116 @@ impl I18n {
     pub fn user_deleted(&self, username: &str) -> String {
         self.get(TextKey::UserDeleted).replace("{}", username)
     }
+    pub fn watched_folders(&self) -> &'static str {
+        self.get(TextKey::WatchedFolders)
+    }
+    pub fn choose_folder(&self) -> &'static str {
+        self.get(TextKey::ChooseFolder)
+    }
+    pub fn recursive(&self) -> &'static str {
+        self.get(TextKey::Recursive)
+    }
+    pub fn include_patterns(&self) -> &'static str {
+        self.get(TextKey::IncludePatterns)
+    }
+    pub fn exclude_patterns(&self) -> &'static str {
+        self.get(TextKey::ExcludePatterns)
+    }
+    pub fn no_watched_folders(&self) -> &'static str {
+        self.get(TextKey::NoWatchedFolders)
+    }
+    pub fn watched_folder_added(&self) -> &'static str {
+        self.get(TextKey::WatchedFolderAdded)
+    }
+    pub fn watched_folder_removed(&self) -> &'static str {
+        self.get(TextKey::WatchedFolderRemoved)
+    }
+    pub fn invalid_watched_folder(&self, error: &str) -> String {
+        self.get(TextKey::InvalidWatchedFolder).replace("{}", error)
+    }
+    pub fn auto_upload_queued(&self, count: usize, user: &str) -> String {
+        self.get(TextKey::AutoUploadQueued)
+            .replacen("{}", &count.to_string(), 1)
+            .replacen("{}", user, 1)
+    }
+    pub fn auto_upload_finished(&self, uploaded: usize, skipped: usize, failed: usize) -> String {
+        self.get(TextKey::AutoUploadFinished)
+            .replacen("{}", &uploaded.to_string(), 1)
+            .replacen("{}", &skipped.to_string(), 1)
+            .replacen("{}", &failed.to_string(), 1)
+    }
+    pub fn auto_upload_error(&self, user: &str, error: &str) -> String {
+        self.get(TextKey::AutoUploadError)
+            .replacen("{}", user, 1)
+            .replacen("{}", error, 1)
+    }
+    pub fn watcher_start_failed(&self, error: &str) -> String {
+        self.get(TextKey::WatcherStartFailed).replace("{}", error)
+    }
+    pub fn upload_history(&self) -> &'static str {
+        self.get(TextKey::UploadHistory)
+    }
+    pub fn search_history(&self) -> &'static str {
+        self.get(TextKey::SearchHistory)
+    }
+    pub fn all_outcomes(&self) -> &'static str {
+        self.get(TextKey::AllOutcomes)
+    }
+    pub fn outcome_uploaded(&self) -> &'static str {
+        self.get(TextKey::OutcomeUploaded)
+    }
+    pub fn outcome_duplicate(&self) -> &'static str {
+        self.get(TextKey::OutcomeDuplicate)
+    }
+    pub fn outcome_failed(&self) -> &'static str {
+        self.get(TextKey::OutcomeFailed)
+    }
+    pub fn no_history(&self) -> &'static str {
+        self.get(TextKey::NoHistory)
+    }
+    pub fn history_count(&self, count: usize) -> String {
+        self.get(TextKey::HistoryCount)
+            .replace("{}", &count.to_string())
+    }
+    pub fn load_history_failed(&self, error: &str) -> String {
+        self.get(TextKey::LoadHistoryFailed).replace("{}", error)
+    }
+    pub fn reopen_failed(&self, count: usize) -> String {
+        self.get(TextKey::ReopenFailed)
+            .replace("{}", &count.to_string())
+    }
+    pub fn reopen(&self) -> &'static str {
+        self.get(TextKey::Reopen)
+    }
+    pub fn resume_queue_prompt(&self, count: usize, user: &str) -> String {
+        self.get(TextKey::ResumeQueuePrompt)
+            .replacen("{}", &count.to_string(), 1)
+            .replacen("{}", user, 1)
+    }
+    pub fn resume_upload(&self) -> &'static str {
+        self.get(TextKey::ResumeUpload)
+    }
+    pub fn discard_queue(&self) -> &'static str {
+        self.get(TextKey::DiscardQueue)
+    }
+    pub fn discard_queue_failed(&self, error: &str) -> String {
+        self.get(TextKey::DiscardQueueFailed).replace("{}", error)
+    }
+    pub fn pause_upload(&self) -> &'static str {
+        self.get(TextKey::PauseUpload)
+    }
+    pub fn cancel_upload(&self) -> &'static str {
+        self.get(TextKey::CancelUpload)
+    }
+    pub fn upload_paused(&self) -> &'static str {
+        self.get(TextKey::UploadPaused)
+    }
+    pub fn cancelling_upload(&self) -> &'static str {
+        self.get(TextKey::CancellingUpload)
+    }
+    pub fn upload_cancelled(&self, uploaded: usize, remaining: usize) -> String {
+        self.get(TextKey::UploadCancelled)
+            .replacen("{}", &uploaded.to_string(), 1)
+            .replacen("{}", &remaining.to_string(), 1)
+    }
 }
@@ -1,86 +1,455 @@
+mod client;
+mod error;
+mod models;
+
+pub use client::{ApiError, ImmichClient};
+pub use error::UploadError;
+pub use models::{
+    AccountInfo, Album, AlbumAssetResult, AssetUpload, AssetUploadResponse, BulkUploadCheckItem,
+    BulkUploadCheckResult, ServerVersion,
+};
+
 use anyhow::{Context, Result};
+use futures_util::TryStreamExt;
 use reqwest::{Body, multipart};
-use std::path::PathBuf;
+use sha1::{Digest, Sha1};
+use std::collections::HashMap;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 use tokio::fs::File;
 use tokio::io::AsyncReadExt;
 use tokio_util::codec::{BytesCodec, FramedRead};
 
-use chrono::{SecondsFormat, Utc};
+use crate::core::media::find_sidecar;
+use crate::core::metadata::read_capture_time;
+use crate::core::retry::RetryConfig;
+use crate::core::throttle::throttle;
+
+/// 每次 bulk-upload-check 请求包含的最大文件数
+const CHECK_BATCH_SIZE: usize = 1000;
+
+/// 两次进度回调之间的最小间隔
+const PROGRESS_INTERVAL: Duration = Duration::from_millis(200);
+
+/// 上传进度回调，参数为已发送字节数和文件总字节数
+pub type ProgressFn = Arc<dyn Fn(u64, u64) + Send + Sync>;
+
+/// 去重检查结果
+#[derive(Debug, Default)]
+pub struct DuplicateCheck {
+    /// 需要上传的文件
+    pub to_upload: Vec<PathBuf>,
+    /// 服务器上已存在的文件
+    pub duplicates: Vec<PathBuf>,
+    /// 服务器上已存在文件对应的资源 ID
+    pub duplicate_asset_ids: HashMap<PathBuf, String>,
+    /// 已计算的校验和，上传时复用
+    pub checksums: HashMap<PathBuf, String>,
+}
+
+/// 连接测试结果
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConnectionInfo {
+    pub version: ServerVersion,
+    /// 提供了 API Key 时的账户信息
+    pub account: Option<AccountInfo>,
+}
 
-pub async fn upload_asst(server_url: &str, api_key: &str, file_path: PathBuf) -> Result<()> {
+/// 上传前准备好的文件信息，在多次重试之间复用
+struct PreparedAsset {
+    path: PathBuf,
+    mime_type: String,
+    size: u64,
+    upload: AssetUpload,
+    /// XMP 元数据文件，体积较小，读入内存后在重试之间复用
+    sidecar: Option<Sidecar>,
+}
+
+struct Sidecar {
+    file_name: String,
+    data: Vec<u8>,
+}
+
+/// 上传单个文件，遇到网络错误、超时、429 和 5xx 响应时按重试策略自动重试
+///
+/// 成功时返回服务器上的资源 ID。`checksum` 为空时会重新计算。
+/// `live_photo_video_id` 为已上传的 Live Photo 视频，服务器会将两者显示为一个资源。
+/// `on_progress` 会在发送数据时被节流调用，重试时进度从 0 重新开始
+pub async fn upload_asst(
+    client: &ImmichClient,
+    file_path: PathBuf,
+    checksum: Option<String>,
+    live_photo_video_id: Option<String>,
+    retry: &RetryConfig,
+    on_progress: Option<ProgressFn>,
+) -> std::result::Result<String, UploadError> {
     tracing::debug!("Starting upload for file: {:?}", file_path);
 
-    let client = reqwest::Client::new();
-    let url = format!("{}/api/assets", server_url.trim_end_matches("/"));
+    let asset = prepare_asset(file_path, checksum, live_photo_video_id)
+        .await
+        .map_err(UploadError::io)?;
+    let file_name = &asset.upload.file_name;
+    let max_attempts = retry.max_attempts.max(1);
+    let mut attempt = 1;
+
+    loop {
+        match try_upload(client, &asset, on_progress.clone()).await {
+            Ok(asset_id) => {
+                tracing::info!("File uploaded successfully: {} ({})", file_name, asset_id);
+                return Ok(asset_id);
+            }
+            Err(e) if e.is_retryable() && attempt < max_attempts => {
+                let delay = retry.delay_after(attempt, e.retry_after());
+                tracing::warn!(
+                    "Upload attempt {}/{} for {} failed: {}, retrying in {:?}",
+                    attempt,
+                    max_attempts,
+                    file_name,
+                    e,
+                    delay
+                );
+                tokio::time::sleep(delay).await;
+                attempt += 1;
+            }
+            Err(e) => {
+                tracing::error!(
+                    "Upload attempt {}/{} for {} failed, giving up: {}",
+                    attempt,
+                    max_attempts,
+                    file_name,
+                    e
+                );
+                return Err(match e {
+                    UploadAttemptError::Local(e) => UploadError::io(e),
+                    UploadAttemptError::Api(e) => e.into(),
+                });
+            }
+        }
+    }
+}
+
+/// 读取上传所需的文件信息：MIME 类型、拍摄时间、校验和以及 XMP 元数据文件
+async fn prepare_asset(
+    file_path: PathBuf,
+    checksum: Option<String>,
+    live_photo_video_id: Option<String>,
+) -> Result<PreparedAsset> {
     let file_name = file_path
         .file_name()
         .and_then(|n| n.to_str())
-        .context("Invalid file name")?;
+        .context("Invalid file name")?
+        .to_string();
 
     let mime_type = get_mime_type(&file_path).await?;
     tracing::debug!("MIME type for {}: {}", file_name, mime_type);
 
-    let file_metadata = std::fs::metadata(&file_path).context("Failed to read file metadata")?;
-    let file_size = file_metadata.len();
+    let metadata = std::fs::metadata(&file_path).context("Failed to read file metadata")?;
+    let modified = metadata.modified().unwrap_or(std::time::SystemTime::now());
+    let capture_path = file_path.clone();
+    let capture = tokio::task::spawn_blocking(move || read_capture_time(&capture_path, modified))
+        .await
+        .context("Capture time task failed")?;
+    let checksum = match checksum {
+        Some(checksum) => checksum,
+        None => compute_checksum(&file_path).await?,
+    };
+    let sidecar = match find_sidecar(&file_path) {
+        Some(sidecar_path) => {
+            tracing::debug!("Attaching sidecar {:?} to {}", sidecar_path, file_name);
+            Some(Sidecar {
+                file_name: sidecar_path
+                    .file_name()
+                    .map(|name| name.to_string_lossy().to_string())
+                    .unwrap_or_default(),
+                data: tokio::fs::read(&sidecar_path)
+                    .await
+                    .context("Failed to read sidecar file")?,
+            })
+        }
+        None => None,
+    };
+    Ok(PreparedAsset {
+        path: file_path,
+        mime_type,
+        size: metadata.len(),
+        sidecar,
+        upload: AssetUpload {
+            // 基于内容生成，文件改名或不同相机的同名文件都不会影响
+            device_asset_id: format!("sha1-{}", checksum),
+            device_id: "rimmich-desktop".to_string(),
+            file_name,
+            file_created_at: capture.time,
+            file_modified_at: modified.into(),
+            checksum,
+            live_photo_video_id,
+        },
+    })
+}
 
-    let file = File::open(&file_path)
+/// 单次上传尝试，打开文件或设置 MIME 类型失败时直接返回不可重试的错误
+async fn try_upload(
+    client: &ImmichClient,
+    asset: &PreparedAsset,
+    on_progress: Option<ProgressFn>,
+) -> std::result::Result<String, UploadAttemptError> {
+    let file_size = asset.size;
+    let file = File::open(&asset.path)
         .await
-        .context("Failed to open file")?;
-    let stream = FramedRead::new(file, BytesCodec::new());
-    let body = Body::wrap_stream(stream);
-
-    let created_at: chrono::DateTime<Utc> = file_metadata
-        .created()
-        .unwrap_or(std::time::SystemTime::now())
-        .into();
-    let modified_at: chrono::DateTime<Utc> = file_metadata
-        .modified()
-        .unwrap_or(std::time::SystemTime::now())
-        .into();
-    let created_at_string = created_at.to_rfc3339_opts(SecondsFormat::Millis, true);
-    let modified_at_string = modified_at.to_rfc3339_opts(SecondsFormat::Millis, true);
-    let device_asset_id = format!("{}-{}", file_name, file_size);
-
-    let file_part = multipart::Part::stream(body)
-        .file_name(file_name.to_string())
-        .mime_str(&mime_type)
-        .context("Failed to set MIME type")?;
-
-    let form = multipart::Form::new()
-        .text("deviceAssetId", device_asset_id)
-        .text("deviceId", "rimmich-desktop")
-        .text("fileCreatedAt", created_at_string)
-        .text("fileModifiedAt", modified_at_string)
-        .text("isFavorite", "false")
-        .text("isReadOnly", "false")
-        .text("filename", file_name.to_string())
-        .part("assetData", file_part);
-
-    let resp = client
-        .post(url)
-        .header("x-api-key", api_key)
-        .multipart(form)
-        .send()
+        .context("Failed to open file")
+        .map_err(UploadAttemptError::Local)?;
+    let mut sent = 0;
+    let mut last_report = Instant::now();
+    let stream = FramedRead::new(file, BytesCodec::new())
+        // 限速在所有并发上传之间共享
+        .and_then(|chunk| async move {
+            throttle(chunk.len()).await;
+            Ok(chunk)
+        })
+        .inspect_ok(move |chunk| {
+            sent += chunk.len() as u64;
+            if let Some(on_progress) = &on_progress
+                && (sent >= file_size || last_report.elapsed() >= PROGRESS_INTERVAL)
+            {
+                last_report = Instant::now();
+                on_progress(sent, file_size);
+            }
+        });
+
+    let file_part = multipart::Part::stream(Body::wrap_stream(stream))
+        .file_name(asset.upload.file_name.clone())
+        .mime_str(&asset.mime_type)
+        .context("Failed to set MIME type")
+        .map_err(UploadAttemptError::Local)?;
+
+    let sidecar_part = match &asset.sidecar {
+        Some(sidecar) => Some(
+            multipart::Part::bytes(sidecar.data.clone())
+                .file_name(sidecar.file_name.clone())
+                .mime_str("application/xml")
+                .context("Failed to set sidecar MIME type")
+                .map_err(UploadAttemptError::Local)?,
+        ),
+        None => None,
+    };
+
+    let response = client
+        .upload_asset(&asset.upload, file_part, sidecar_part)
         .await
-        .context("Failed to send upload request")?;
+        .map_err(UploadAttemptError::Api)?;
+    if response.is_duplicate() {
+        tracing::debug!("Server reported {} as duplicate", asset.upload.file_name);
+    }
+    Ok(response.id)
+}
+
+/// 单次上传尝试的错误
+#[derive(Debug)]
+enum UploadAttemptError {
+    /// 本地文件错误，不可重试
+    Local(anyhow::Error),
+    Api(ApiError),
+}
 
-    let status = resp.status();
-    if status.is_success() {
-        tracing::info!("File uploaded successfully: {}", file_name);
-        Ok(())
+impl UploadAttemptError {
+    fn is_retryable(&self) -> bool {
+        matches!(self, UploadAttemptError::Api(e) if e.is_retryable())
+    }
+
+    fn retry_after(&self) -> Option<Duration> {
+        match self {
+            UploadAttemptError::Api(e) => e.retry_after(),
+            UploadAttemptError::Local(_) => None,
+        }
+    }
+}
+
+impl std::fmt::Display for UploadAttemptError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            UploadAttemptError::Local(e) => write!(f, "{:#}", e),
+            UploadAttemptError::Api(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+/// 测试服务器连接：检查 ping 和版本，提供 API Key 时同时读取账户信息
+pub async fn test_connection(client: &ImmichClient) -> Result<ConnectionInfo> {
+    let pong = client
+        .ping()
+        .await
+        .with_context(|| format!("Failed to connect to {}", client.base_url()))?;
+    if !pong {
+        anyhow::bail!("Unexpected ping response from {}", client.base_url());
+    }
+    let version = client
+        .server_version()
+        .await
+        .context("Failed to read server version")?;
+
+    let account = if client.has_api_key() {
+        Some(client.my_user().await.context("Failed to verify API Key")?)
     } else {
-        let error_text = resp
-            .text()
-            .await
-            .unwrap_or_else(|_| "Failed to read error messages".to_string());
-        tracing::error!(
-            "File upload failed for {}: status {}, error: {}",
-            file_name,
-            status,
-            error_text
-        );
-        anyhow::bail!("Upload failed with status {}: {}", status, error_text)
+        None
+    };
+
+    tracing::info!(
+        "Connection test to {} succeeded: {}",
+        client.base_url(),
+        version
+    );
+    Ok(ConnectionInfo { version, account })
+}
+
+/// 计算文件的 SHA-1 校验和（十六进制）
+pub async fn compute_checksum(file_path: &Path) -> Result<String> {
+    let file_path = file_path.to_path_buf();
+    tokio::task::spawn_blocking(move || {
+        let mut file =
+            std::fs::File::open(&file_path).context("Failed to open file for checksum")?;
+        let mut hasher = Sha1::new();
+        let mut buffer = vec![0; 64 * 1024];
+        loop {
+            let bytes_read = file
+                .read(&mut buffer)
+                .context("Failed to read file for checksum")?;
+            if bytes_read == 0 {
+                break;
+            }
+            hasher.update(&buffer[..bytes_read]);
+        }
+        Ok(format!("{:x}", hasher.finalize()))
+    })
+    .await
+    .context("Checksum task failed")?
+}
+
+/// 计算校验和并通过 bulk-upload-check 接口过滤服务器上已存在的文件
+///
+/// 无法计算校验和的文件会保留在待上传列表中，由上传阶段报告具体错误
+pub async fn check_duplicates(
+    client: &ImmichClient,
+    files: Vec<PathBuf>,
+    concurrency: usize,
+) -> Result<DuplicateCheck> {
+    let semaphore = std::sync::Arc::new(tokio::sync::Semaphore::new(concurrency.max(1)));
+    let mut tasks = tokio::task::JoinSet::new();
+    for (index, path) in files.iter().enumerate() {
+        let semaphore = semaphore.clone();
+        let path = path.clone();
+        tasks.spawn(async move {
+            let _permit = semaphore.acquire_owned().await;
+            (index, compute_checksum(&path).await)
+        });
+    }
+
+    let mut checksums = vec![None; files.len()];
+    while let Some(result) = tasks.join_next().await {
+        let (index, checksum) = result.context("Checksum task failed")?;
+        match checksum {
+            Ok(checksum) => checksums[index] = Some(checksum),
+            Err(e) => tracing::warn!("Failed to compute checksum for {:?}: {}", files[index], e),
+        }
+    }
+
+    let items: Vec<BulkUploadCheckItem> = checksums
+        .iter()
+        .enumerate()
+        .filter_map(|(index, checksum)| {
+            checksum.as_ref().map(|checksum| BulkUploadCheckItem {
+                id: index.to_string(),
+                checksum: checksum.clone(),
+            })
+        })
+        .collect();
+
+    let mut is_duplicate = vec![false; files.len()];
+    let mut duplicate_asset_ids = HashMap::new();
+
+    for batch in items.chunks(CHECK_BATCH_SIZE) {
+        let results = client.bulk_upload_check(batch).await?;
+        for result in results {
+            if result.is_duplicate()
+                && let Some(index) = result.id.parse::<usize>().ok()
+                && let Some(flag) = is_duplicate.get_mut(index)
+            {
+                *flag = true;
+                if let Some(asset_id) = result.asset_id {
+                    duplicate_asset_ids.insert(files[index].clone(), asset_id);
+                }
+            }
+        }
+    }
+
+    let mut check = DuplicateCheck {
+        duplicate_asset_ids,
+        checksums: files
+            .iter()
+            .cloned()
+            .zip(checksums)
+            .filter_map(|(path, checksum)| checksum.map(|checksum| (path, checksum)))
+            .collect(),
+        ..Default::default()
+    };
+    for (path, duplicate) in files.into_iter().zip(is_duplicate) {
+        if duplicate {
+            tracing::debug!("Skipping duplicate file: {:?}", path);
+            check.duplicates.push(path);
+        } else {
+            check.to_upload.push(path);
+        }
+    }
+    tracing::info!(
+        "Duplicate check finished: {} to upload, {} duplicates",
+        check.to_upload.len(),
+        check.duplicates.len()
+    );
+
+    Ok(check)
+}
+
+/// 按名称查找相册，不存在时自动创建
+pub async fn find_or_create_album(client: &ImmichClient, album_name: &str) -> Result<Album> {
+    let album_name = album_name.trim();
+    let albums = client.list_albums().await?;
+    if let Some(album) = albums
+        .into_iter()
+        .find(|album| album.album_name == album_name)
+    {
+        return Ok(album);
+    }
+
+    let album = client.create_album(album_name).await?;
+    tracing::info!("Album created: {} ({})", album.album_name, album.id);
+    Ok(album)
+}
+
+/// 将资源添加到相册，已在相册中的资源会被忽略
+pub async fn add_assets_to_album(
+    client: &ImmichClient,
+    album_id: &str,
+    asset_ids: &[String],
+) -> Result<()> {
+    if asset_ids.is_empty() {
+        return Ok(());
+    }
+
+    let results = client.add_assets_to_album(album_id, asset_ids).await?;
+    for result in results {
+        match result.error.as_deref() {
+            _ if result.success => {}
+            Some("duplicate") => {
+                tracing::debug!("Asset {} is already in album {}", result.id, album_id)
+            }
+            error => anyhow::bail!(
+                "Failed to add asset {} to album: {}",
+                result.id,
+                error.unwrap_or("unknown error")
+            ),
+        }
     }
+    Ok(())
 }
 
 async fn get_mime_type(file_path: &PathBuf) -> Result<String> {
@@ -0,0 +1,364 @@
+use super::error::is_quota_error;
+use super::models::{
+    AccountInfo, Album, AlbumAssetResult, AlbumAssetsRequest, AssetUpload, AssetUploadResponse,
+    BulkUploadCheckItem, BulkUploadCheckRequest, BulkUploadCheckResponse, BulkUploadCheckResult,
+    CreateAlbumRequest, ErrorResponse, PingResponse, ServerVersion,
+};
+use crate::core::config::ServerProfile;
+use crate::core::retry::{is_retryable_error, is_retryable_status};
+use anyhow::{Context, Result};
+use chrono::{DateTime, SecondsFormat, Utc};
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use reqwest::{Method, RequestBuilder, Response, StatusCode, multipart};
+use serde::de::DeserializeOwned;
+use std::fmt;
+use std::time::Duration;
+
+/// 建立连接的超时时间
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// 单次读取响应的超时时间，上传大文件时服务器可能需要较长时间处理
+const READ_TIMEOUT: Duration = Duration::from_secs(120);
+
+const USER_AGENT: &str = concat!("rimmich/", env!("CARGO_PKG_VERSION"));
+
+/// Immich API 请求错误
+#[derive(Debug)]
+pub enum ApiError {
+    /// 请求未完成：连接失败、超时或传输中断
+    Transport(reqwest::Error),
+    /// 服务器返回了错误状态码
+    Status {
+        status: StatusCode,
+        message: String,
+        /// 服务器通过 Retry-After 要求的等待时间
+        retry_after: Option<Duration>,
+    },
+    /// 响应内容无法解析
+    Decode(reqwest::Error),
+}
+
+impl ApiError {
+    /// 是否值得重试：网络错误、超时、429 和 5xx 响应，超出配额的 507 重试也不会成功
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            ApiError::Transport(e) => is_retryable_error(e),
+            ApiError::Status {
+                status, message, ..
+            } => is_retryable_status(*status) && !is_quota_error(*status, message),
+            ApiError::Decode(_) => false,
+        }
+    }
+
+    pub fn retry_after(&self) -> Option<Duration> {
+        match self {
+            ApiError::Status { retry_after, .. } => *retry_after,
+            _ => None,
+        }
+    }
+
+    pub fn status(&self) -> Option<StatusCode> {
+        match self {
+            ApiError::Status { status, .. } => Some(*status),
+            ApiError::Transport(e) | ApiError::Decode(e) => e.status(),
+        }
+    }
+
+    /// 根据错误响应生成错误，优先使用响应中的 message 字段
+    async fn from_response(resp: Response) -> Self {
+        let status = resp.status();
+        let retry_after = resp
+            .headers()
+            .get(reqwest::header::RETRY_AFTER)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| parse_retry_after(value, Utc::now()));
+        let text = resp
+            .text()
+            .await
+            .unwrap_or_else(|_| "Failed to read error messages".to_string());
+        let message = serde_json::from_str::<ErrorResponse>(&text)
+            .ok()
+            .and_then(ErrorResponse::into_message)
+            .unwrap_or(text);
+        ApiError::Status {
+            status,
+            message,
+            retry_after,
+        }
+    }
+}
+
+/// 解析 Retry-After：秒数或 HTTP 日期，日期已过时返回零
+fn parse_retry_after(value: &str, now: DateTime<Utc>) -> Option<Duration> {
+    let value = value.trim();
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+    let date = DateTime::parse_from_rfc2822(value).ok()?;
+    Some(
+        (date.with_timezone(&Utc) - now)
+            .to_std()
+            .unwrap_or_default(),
+    )
+}
+
+impl fmt::Display for ApiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ApiError::Transport(e) => write!(f, "Request failed: {}", e),
+            ApiError::Status {
+                status, message, ..
+            } => write!(f, "Server returned {}: {}", status, message),
+            ApiError::Decode(e) => write!(f, "Failed to parse response: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for ApiError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ApiError::Transport(e) | ApiError::Decode(e) => Some(e),
+            ApiError::Status { .. } => None,
+        }
+    }
+}
+
+/// Immich API 客户端，内部共享连接池，克隆的开销很小
+#[derive(Debug, Clone)]
+pub struct ImmichClient {
+    http: reqwest::Client,
+    base_url: String,
+    has_api_key: bool,
+}
+
+impl ImmichClient {
+    /// 使用指定账户的 API Key 创建客户端
+    pub fn new(server: &ServerProfile, api_key: &str) -> Result<Self> {
+        Self::build(server, Some(api_key))
+    }
+
+    /// 创建不带 API Key 的客户端，只能访问 ping、版本等公开接口
+    pub fn anonymous(server: &ServerProfile) -> Result<Self> {
+        Self::build(server, None)
+    }
+
+    /// 根据服务器配置创建 HTTP 客户端，附加自定义请求头和 TLS 设置
+    fn build(server: &ServerProfile, api_key: Option<&str>) -> Result<Self> {
+        let mut headers = HeaderMap::new();
+        for (name, value) in &server.headers {
+            let name = HeaderName::from_bytes(name.trim().as_bytes())
+                .with_context(|| format!("Invalid header name: {}", name))?;
+            let value = HeaderValue::from_str(value.trim())
+                .with_context(|| format!("Invalid header value for {}", name))?;
+            headers.insert(name, value);
+        }
+        if let Some(api_key) = api_key {
+            let mut value = HeaderValue::from_str(api_key.trim()).context("Invalid API Key")?;
+            value.set_sensitive(true);
+            headers.insert("x-api-key", value);
+        }
+
+        let mut builder = reqwest::Client::builder()
+            .user_agent(USER_AGENT)
+            .default_headers(headers)
+            .connect_timeout(CONNECT_TIMEOUT)
+            .read_timeout(READ_TIMEOUT)
+            .tls_danger_accept_invalid_certs(server.accept_invalid_certs);
+        if let Some(ca_certificate) = &server.ca_certificate {
+            let pem = std::fs::read(ca_certificate)
+                .with_context(|| format!("Failed to read CA certificate: {:?}", ca_certificate))?;
+            let certificate =
+                reqwest::Certificate::from_pem(&pem).context("Failed to parse CA certificate")?;
+            builder = builder.tls_certs_merge([certificate]);
+        }
+
+        Ok(Self {
+            http: builder.build().context("Failed to create HTTP client")?,
+            base_url: server.url.trim_end_matches('/').to_string(),
+            has_api_key: api_key.is_some(),
+        })
+    }
+
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    pub fn has_api_key(&self) -> bool {
+        self.has_api_key
+    }
+
+    fn request(&self, method: Method, path: &str) -> RequestBuilder {
+        self.http
+            .request(method, format!("{}/api{}", self.base_url, path))
+    }
+
+    /// 发送请求并解析 JSON 响应
+    async fn send<T: DeserializeOwned>(&self, request: RequestBuilder) -> Result<T, ApiError> {
+        let resp = request.send().await.map_err(ApiError::Transport)?;
+        if !resp.status().is_success() {
+            return Err(ApiError::from_response(resp).await);
+        }
+        resp.json().await.map_err(ApiError::Decode)
+    }
+
+    /// 检查服务器是否可以访问
+    pub async fn ping(&self) -> Result<bool, ApiError> {
+        let response: PingResponse = self.send(self.request(Method::GET, "/server/ping")).await?;
+        Ok(response.res == "pong")
+    }
+
+    pub async fn server_version(&self) -> Result<ServerVersion, ApiError> {
+        self.send(self.request(Method::GET, "/server/version"))
+            .await
+    }
+
+    /// 当前 API Key 对应的账户
+    pub async fn my_user(&self) -> Result<AccountInfo, ApiError> {
+        self.send(self.request(Method::GET, "/users/me")).await
+    }
+
+    /// 按校验和检查文件是否已存在于服务器
+    pub async fn bulk_upload_check(
+        &self,
+        assets: &[BulkUploadCheckItem],
+    ) -> Result<Vec<BulkUploadCheckResult>, ApiError> {
+        let response: BulkUploadCheckResponse = self
+            .send(
+                self.request(Method::POST, "/assets/bulk-upload-check")
+                    .json(&BulkUploadCheckRequest { assets }),
+            )
+            .await?;
+        Ok(response.results)
+    }
+
+    /// 上传资源，`data` 为文件内容，`sidecar` 为可选的 XMP 元数据文件
+    pub async fn upload_asset(
+        &self,
+        asset: &AssetUpload,
+        data: multipart::Part,
+        sidecar: Option<multipart::Part>,
+    ) -> Result<AssetUploadResponse, ApiError> {
+        let mut form = multipart::Form::new()
+            .text("deviceAssetId", asset.device_asset_id.clone())
+            .text("deviceId", asset.device_id.clone())
+            .text(
+                "fileCreatedAt",
+                asset
+                    .file_created_at
+                    .to_rfc3339_opts(SecondsFormat::Millis, true),
+            )
+            .text(
+                "fileModifiedAt",
+                asset
+                    .file_modified_at
+                    .to_rfc3339_opts(SecondsFormat::Millis, true),
+            )
+            .text("isFavorite", "false")
+            .text("isReadOnly", "false")
+            .text("filename", asset.file_name.clone())
+            .part("assetData", data);
+        if let Some(video_id) = &asset.live_photo_video_id {
+            form = form.text("livePhotoVideoId", video_id.clone());
+        }
+        if let Some(sidecar) = sidecar {
+            form = form.part("sidecarData", sidecar);
+        }
+
+        self.send(
+            self.request(Method::POST, "/assets")
+                .header("x-immich-checksum", &asset.checksum)
+                .multipart(form),
+        )
+        .await
+    }
+
+    /// 获取用户可见的所有相册，按名称排序
+    pub async fn list_albums(&self) -> Result<Vec<Album>, ApiError> {
+        let mut albums: Vec<Album> = self.send(self.request(Method::GET, "/albums")).await?;
+        albums.sort_by(|a, b| a.album_name.cmp(&b.album_name));
+        Ok(albums)
+    }
+
+    pub async fn create_album(&self, album_name: &str) -> Result<Album, ApiError> {
+        self.send(
+            self.request(Method::POST, "/albums")
+                .json(&CreateAlbumRequest { album_name }),
+        )
+        .await
+    }
+
+    /// 将资源添加到相册，返回每个资源的处理结果
+    pub async fn add_assets_to_album(
+        &self,
+        album_id: &str,
+        asset_ids: &[String],
+    ) -> Result<Vec<AlbumAssetResult>, ApiError> {
+        self.send(
+            self.request(Method::PUT, &format!("/albums/{}/assets", album_id))
+                .json(&AlbumAssetsRequest { ids: asset_ids }),
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn now() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2015, 10, 21, 7, 28, 0).unwrap()
+    }
+
+    #[test]
+    fn retry_after_seconds() {
+        assert_eq!(
+            parse_retry_after("120", now()),
+            Some(Duration::from_secs(120))
+        );
+        assert_eq!(
+            parse_retry_after(" 5 ", now()),
+            Some(Duration::from_secs(5))
+        );
+        assert_eq!(parse_retry_after("0", now()), Some(Duration::ZERO));
+    }
+
+    #[test]
+    fn retry_after_http_date() {
+        assert_eq!(
+            parse_retry_after("Wed, 21 Oct 2015 07:29:30 GMT", now()),
+            Some(Duration::from_secs(90))
+        );
+        // 日期已过，立即重试
+        assert_eq!(
+            parse_retry_after("Wed, 21 Oct 2015 07:00:00 GMT", now()),
+            Some(Duration::ZERO)
+        );
+    }
+
+    #[test]
+    fn retry_after_invalid() {
+        for value in ["", "-1", "1.5", "soon", "2015-10-21T07:29:30Z"] {
+            assert_eq!(parse_retry_after(value, now()), None, "{value}");
+        }
+    }
+
+    fn status_error(status: u16, message: &str) -> ApiError {
+        ApiError::Status {
+            status: StatusCode::from_u16(status).unwrap(),
+            message: message.to_string(),
+            retry_after: None,
+        }
+    }
+
+    #[test]
+    fn quota_errors_are_not_retried() {
+        let quota = "Quota has been exceeded!";
+        assert!(!status_error(507, quota).is_retryable());
+        assert!(!status_error(413, quota).is_retryable());
+        assert!(status_error(507, "Insufficient storage").is_retryable());
+        // 5xx 的消息中提到 quota 不代表超出配额
+        assert!(status_error(503, quota).is_retryable());
+        assert!(status_error(429, "Too many requests").is_retryable());
+    }
+}
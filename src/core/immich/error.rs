@@ -0,0 +1,251 @@
+use super::client::ApiError;
+use reqwest::StatusCode;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// 上传失败的原因，界面通过 `I18n::upload_error` 显示本地化的提示
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", content = "detail", rename_all = "snake_case")]
+pub enum UploadError {
+    /// 无法连接服务器或传输中断
+    Network(String),
+    /// 服务器未在规定时间内响应
+    Timeout,
+    /// API Key 无效或已被删除（401）
+    Unauthorized(String),
+    /// API Key 缺少权限（403）
+    Forbidden(String),
+    /// 超出用户的存储配额
+    QuotaExceeded(String),
+    /// 服务器不支持该文件类型
+    UnsupportedMedia(String),
+    /// 服务器返回 5xx、429 或无法解析的响应
+    Server { status: u16, message: String },
+    /// 服务器拒绝了请求，例如请求校验失败
+    Rejected { status: u16, message: String },
+    /// 读取本地文件失败
+    Io(String),
+    /// 其他错误，例如任务异常退出
+    Other(String),
+}
+
+impl UploadError {
+    /// 本地错误，包含完整的错误链
+    pub fn io(error: anyhow::Error) -> Self {
+        UploadError::Io(format!("{:#}", error))
+    }
+}
+
+/// 拼接错误及其来源，reqwest 的错误信息通常在来源中
+fn error_chain(error: &dyn std::error::Error) -> String {
+    let mut message = error.to_string();
+    let mut source = error.source();
+    while let Some(cause) = source {
+        message.push_str(": ");
+        message.push_str(&cause.to_string());
+        source = cause.source();
+    }
+    message
+}
+
+/// 是否为配额错误：只有拒绝请求的状态码和 507（存储空间不足）可能是，
+/// 其他 5xx 的消息中提到 quota 不代表超出配额
+pub(super) fn is_quota_error(status: StatusCode, message: &str) -> bool {
+    matches!(
+        status,
+        StatusCode::BAD_REQUEST
+            | StatusCode::FORBIDDEN
+            | StatusCode::PAYLOAD_TOO_LARGE
+            | StatusCode::INSUFFICIENT_STORAGE
+    ) && message.to_lowercase().contains("quota")
+}
+
+impl From<ApiError> for UploadError {
+    fn from(error: ApiError) -> Self {
+        match error {
+            ApiError::Transport(e) if e.is_timeout() => UploadError::Timeout,
+            ApiError::Transport(e) => UploadError::Network(error_chain(&e)),
+            ApiError::Decode(e) => UploadError::Server {
+                status: e.status().map(|status| status.as_u16()).unwrap_or_default(),
+                message: error_chain(&e),
+            },
+            ApiError::Status {
+                status, message, ..
+            } => {
+                let lower = message.to_lowercase();
+                match status {
+                    StatusCode::UNAUTHORIZED => UploadError::Unauthorized(message),
+                    _ if is_quota_error(status, &message) => UploadError::QuotaExceeded(message),
+                    StatusCode::FORBIDDEN => UploadError::Forbidden(message),
+                    StatusCode::UNSUPPORTED_MEDIA_TYPE => UploadError::UnsupportedMedia(message),
+                    StatusCode::BAD_REQUEST if lower.contains("unsupported") => {
+                        UploadError::UnsupportedMedia(message)
+                    }
+                    StatusCode::REQUEST_TIMEOUT | StatusCode::GATEWAY_TIMEOUT => {
+                        UploadError::Timeout
+                    }
+                    _ if status.is_client_error() && status != StatusCode::TOO_MANY_REQUESTS => {
+                        UploadError::Rejected {
+                            status: status.as_u16(),
+                            message,
+                        }
+                    }
+                    _ => UploadError::Server {
+                        status: status.as_u16(),
+                        message,
+                    },
+                }
+            }
+        }
+    }
+}
+
+impl fmt::Display for UploadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            UploadError::Network(message) => write!(f, "Network error: {}", message),
+            UploadError::Timeout => write!(f, "Request timed out"),
+            UploadError::Unauthorized(message) => write!(f, "Unauthorized: {}", message),
+            UploadError::Forbidden(message) => write!(f, "Forbidden: {}", message),
+            UploadError::QuotaExceeded(message) => write!(f, "Quota exceeded: {}", message),
+            UploadError::UnsupportedMedia(message) => {
+                write!(f, "Unsupported media type: {}", message)
+            }
+            UploadError::Server { status, message } => {
+                write!(f, "Server error {}: {}", status, message)
+            }
+            UploadError::Rejected { status, message } => {
+                write!(f, "Rejected with status {}: {}", status, message)
+            }
+            UploadError::Io(message) => write!(f, "File error: {}", message),
+            UploadError::Other(message) => f.write_str(message),
+        }
+    }
+}
+
+impl std::error::Error for UploadError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn status_error(status: u16, message: &str) -> UploadError {
+        ApiError::Status {
+            status: StatusCode::from_u16(status).unwrap(),
+            message: message.to_string(),
+            retry_after: None,
+        }
+        .into()
+    }
+
+    #[test]
+    fn maps_status_codes() {
+        assert_eq!(
+            status_error(401, "Invalid API key"),
+            UploadError::Unauthorized("Invalid API key".to_string())
+        );
+        assert_eq!(
+            status_error(403, "Missing permission: asset.upload"),
+            UploadError::Forbidden("Missing permission: asset.upload".to_string())
+        );
+        assert_eq!(
+            status_error(413, "Payload Too Large"),
+            UploadError::Rejected {
+                status: 413,
+                message: "Payload Too Large".to_string()
+            }
+        );
+        assert_eq!(
+            status_error(415, "Unsupported file type"),
+            UploadError::UnsupportedMedia("Unsupported file type".to_string())
+        );
+        assert_eq!(
+            status_error(400, "Unsupported file type image/x-foo"),
+            UploadError::UnsupportedMedia("Unsupported file type image/x-foo".to_string())
+        );
+        assert_eq!(status_error(408, "Request Timeout"), UploadError::Timeout);
+        assert_eq!(status_error(504, "Gateway Timeout"), UploadError::Timeout);
+    }
+
+    #[test]
+    fn maps_retryable_statuses_to_server_error() {
+        for status in [429, 500, 502, 503] {
+            assert_eq!(
+                status_error(status, "try again"),
+                UploadError::Server {
+                    status,
+                    message: "try again".to_string()
+                }
+            );
+        }
+    }
+
+    #[test]
+    fn maps_quota_message() {
+        assert_eq!(
+            status_error(400, "Quota has been exceeded!"),
+            UploadError::QuotaExceeded("Quota has been exceeded!".to_string())
+        );
+    }
+
+    #[test]
+    fn quota_message_mapping_table() {
+        let quota = "Quota has been exceeded!";
+        let cases = [
+            (400, UploadError::QuotaExceeded(quota.to_string())),
+            (403, UploadError::QuotaExceeded(quota.to_string())),
+            (413, UploadError::QuotaExceeded(quota.to_string())),
+            (507, UploadError::QuotaExceeded(quota.to_string())),
+            (401, UploadError::Unauthorized(quota.to_string())),
+            (
+                500,
+                UploadError::Server {
+                    status: 500,
+                    message: quota.to_string(),
+                },
+            ),
+            (
+                503,
+                UploadError::Server {
+                    status: 503,
+                    message: quota.to_string(),
+                },
+            ),
+        ];
+        for (status, expected) in cases {
+            assert_eq!(status_error(status, quota), expected, "{status}");
+        }
+        // 没有提到配额的 507 仍是服务器错误
+        assert_eq!(
+            status_error(507, "Insufficient Storage"),
+            UploadError::Server {
+                status: 507,
+                message: "Insufficient Storage".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn upload_error_serde_round_trip() {
+        for error in [
+            UploadError::Timeout,
+            UploadError::Unauthorized("Invalid API key".to_string()),
+            UploadError::Server {
+                status: 503,
+                message: "unavailable".to_string(),
+            },
+        ] {
+            let json = serde_json::to_string(&error).unwrap();
+            assert_eq!(serde_json::from_str::<UploadError>(&json).unwrap(), error);
+        }
+        let json = serde_json::to_value(UploadError::Rejected {
+            status: 400,
+            message: "bad".to_string(),
+        })
+        .unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({"kind": "rejected", "detail": {"status": 400, "message": "bad"}})
+        );
+    }
+}
@@ -0,0 +1,285 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// bulk-upload-check 中的单个文件
+#[derive(Debug, Clone, Serialize)]
+pub struct BulkUploadCheckItem {
+    pub id: String,
+    pub checksum: String,
+}
+
+#[derive(Debug, Serialize)]
+pub(super) struct BulkUploadCheckRequest<'a> {
+    pub assets: &'a [BulkUploadCheckItem],
+}
+
+/// bulk-upload-check 中单个文件的检查结果
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BulkUploadCheckResult {
+    pub id: String,
+    /// `accept` 或 `reject`
+    pub action: String,
+    #[serde(default)]
+    pub reason: Option<String>,
+    /// 服务器上已存在的资源 ID
+    #[serde(default)]
+    pub asset_id: Option<String>,
+}
+
+impl BulkUploadCheckResult {
+    /// 是否因服务器上已存在相同文件而被拒绝
+    pub fn is_duplicate(&self) -> bool {
+        self.action == "reject" && self.reason.as_deref() == Some("duplicate")
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub(super) struct BulkUploadCheckResponse {
+    pub results: Vec<BulkUploadCheckResult>,
+}
+
+/// 上传资源时随文件提交的字段
+#[derive(Debug, Clone)]
+pub struct AssetUpload {
+    pub device_asset_id: String,
+    pub device_id: String,
+    pub file_name: String,
+    pub file_created_at: DateTime<Utc>,
+    pub file_modified_at: DateTime<Utc>,
+    /// 文件内容的 SHA-1 校验和，通过 x-immich-checksum 请求头发送
+    pub checksum: String,
+    /// Live Photo 对应视频的资源 ID
+    pub live_photo_video_id: Option<String>,
+}
+
+/// 上传资源的响应
+#[derive(Debug, Clone, Deserialize)]
+pub struct AssetUploadResponse {
+    pub id: String,
+    /// `created` 或 `duplicate`
+    pub status: String,
+}
+
+impl AssetUploadResponse {
+    pub fn is_duplicate(&self) -> bool {
+        self.status == "duplicate"
+    }
+}
+
+/// 服务器上的相册
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Album {
+    pub id: String,
+    pub album_name: String,
+    #[serde(default)]
+    pub asset_count: u64,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct CreateAlbumRequest<'a> {
+    pub album_name: &'a str,
+}
+
+#[derive(Debug, Serialize)]
+pub(super) struct AlbumAssetsRequest<'a> {
+    pub ids: &'a [String],
+}
+
+/// 向相册添加单个资源的结果
+#[derive(Debug, Clone, Deserialize)]
+pub struct AlbumAssetResult {
+    pub id: String,
+    pub success: bool,
+    /// 失败原因，例如 `duplicate`
+    #[serde(default)]
+    pub error: Option<String>,
+}
+
+/// 服务器版本
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct ServerVersion {
+    pub major: u32,
+    pub minor: u32,
+    pub patch: u32,
+}
+
+impl fmt::Display for ServerVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "v{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub(super) struct PingResponse {
+    pub res: String,
+}
+
+/// API Key 对应的账户信息
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountInfo {
+    pub name: String,
+    pub email: String,
+    /// 存储配额，未设置配额时为空
+    #[serde(default)]
+    pub quota_size_in_bytes: Option<u64>,
+    /// 已使用的存储空间
+    #[serde(default)]
+    pub quota_usage_in_bytes: Option<u64>,
+}
+
+/// 服务器返回的错误信息，校验失败时 message 为多条
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub(super) enum ErrorMessage {
+    One(String),
+    Many(Vec<String>),
+}
+
+/// 服务器返回的错误响应
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct ErrorResponse {
+    #[serde(default)]
+    pub message: Option<ErrorMessage>,
+    #[serde(default)]
+    pub error: Option<String>,
+}
+
+impl ErrorResponse {
+    pub fn into_message(self) -> Option<String> {
+        match self.message {
+            Some(ErrorMessage::One(message)) => Some(message),
+            Some(ErrorMessage::Many(messages)) => Some(messages.join("; ")),
+            None => self.error,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bulk_upload_check_request() {
+        let assets = [BulkUploadCheckItem {
+            id: "/photos/IMG_0001.jpg".to_string(),
+            checksum: "aGVsbG8=".to_string(),
+        }];
+        let json = serde_json::to_value(BulkUploadCheckRequest { assets: &assets }).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({"assets": [{"id": "/photos/IMG_0001.jpg", "checksum": "aGVsbG8="}]})
+        );
+    }
+
+    #[test]
+    fn bulk_upload_check_response() {
+        let json = r#"{"results": [
+            {"id": "a.jpg", "action": "accept"},
+            {"id": "b.jpg", "action": "reject", "reason": "duplicate", "assetId": "c6f1", "isTrashed": false},
+            {"id": "c.jpg", "action": "reject", "reason": "unsupported-format"}
+        ]}"#;
+        let response: BulkUploadCheckResponse = serde_json::from_str(json).unwrap();
+        let results = response.results;
+        assert_eq!(results.len(), 3);
+        assert!(!results[0].is_duplicate());
+        assert!(results[1].is_duplicate());
+        assert_eq!(results[1].asset_id.as_deref(), Some("c6f1"));
+        assert!(!results[2].is_duplicate());
+    }
+
+    #[test]
+    fn asset_upload_response() {
+        let created: AssetUploadResponse =
+            serde_json::from_str(r#"{"id": "9a1b", "status": "created"}"#).unwrap();
+        let duplicate: AssetUploadResponse =
+            serde_json::from_str(r#"{"id": "9a1b", "status": "duplicate"}"#).unwrap();
+        assert_eq!(created.id, "9a1b");
+        assert!(!created.is_duplicate());
+        assert!(duplicate.is_duplicate());
+    }
+
+    #[test]
+    fn album_and_requests() {
+        let json = r#"{
+            "id": "e3b0", "albumName": "Trip", "assetCount": 12, "shared": false,
+            "albumThumbnailAssetId": null, "ownerId": "u1"
+        }"#;
+        let album: Album = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            album,
+            Album {
+                id: "e3b0".to_string(),
+                album_name: "Trip".to_string(),
+                asset_count: 12
+            }
+        );
+        assert_eq!(
+            serde_json::to_value(CreateAlbumRequest { album_name: "Trip" }).unwrap(),
+            serde_json::json!({"albumName": "Trip"})
+        );
+        let ids = ["9a1b".to_string()];
+        assert_eq!(
+            serde_json::to_value(AlbumAssetsRequest { ids: &ids }).unwrap(),
+            serde_json::json!({"ids": ["9a1b"]})
+        );
+        let results: Vec<AlbumAssetResult> = serde_json::from_str(
+            r#"[{"id": "9a1b", "success": true}, {"id": "7f2c", "success": false, "error": "duplicate"}]"#,
+        )
+        .unwrap();
+        assert!(results[0].success);
+        assert_eq!(results[1].error.as_deref(), Some("duplicate"));
+    }
+
+    #[test]
+    fn server_info() {
+        let version: ServerVersion =
+            serde_json::from_str(r#"{"major": 1, "minor": 132, "patch": 3}"#).unwrap();
+        assert_eq!(version.to_string(), "v1.132.3");
+        let ping: PingResponse = serde_json::from_str(r#"{"res": "pong"}"#).unwrap();
+        assert_eq!(ping.res, "pong");
+
+        let json = r#"{
+            "id": "u1", "name": "Alice", "email": "alice@example.com", "isAdmin": false,
+            "quotaSizeInBytes": 1073741824, "quotaUsageInBytes": 536870912
+        }"#;
+        let account: AccountInfo = serde_json::from_str(json).unwrap();
+        assert_eq!(account.quota_size_in_bytes, Some(1_073_741_824));
+        assert_eq!(account.quota_usage_in_bytes, Some(536_870_912));
+        // 未设置配额
+        let json = r#"{"name": "Bob", "email": "bob@example.com", "quotaSizeInBytes": null}"#;
+        let account: AccountInfo = serde_json::from_str(json).unwrap();
+        assert_eq!(account.quota_size_in_bytes, None);
+    }
+
+    #[test]
+    fn error_response_messages() {
+        let message = |json: &str| {
+            serde_json::from_str::<ErrorResponse>(json)
+                .unwrap()
+                .into_message()
+        };
+        assert_eq!(
+            message(
+                r#"{"message": "Invalid API key", "error": "Unauthorized", "statusCode": 401}"#
+            )
+            .as_deref(),
+            Some("Invalid API key")
+        );
+        assert_eq!(
+            message(r#"{"message": ["name must be a string", "id must be a UUID"], "error": "Bad Request"}"#)
+                .as_deref(),
+            Some("name must be a string; id must be a UUID")
+        );
+        assert_eq!(
+            message(r#"{"error": "Not Found", "statusCode": 404}"#).as_deref(),
+            Some("Not Found")
+        );
+        assert_eq!(message(r#"{"statusCode": 500}"#), None);
+    }
+}
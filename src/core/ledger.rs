@@ -0,0 +1,235 @@
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs::{self, OpenOptions};
+use std::io::{BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+use std::time::UNIX_EPOCH;
+
+/// 已上传文件的标识：同一服务器和用户下，路径、大小和修改时间都相同即视为同一个文件
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+struct LedgerKey {
+    server: String,
+    user: String,
+    path: PathBuf,
+    size: u64,
+    /// 修改时间（Unix 毫秒）
+    modified: i64,
+}
+
+impl LedgerKey {
+    /// 读取文件的大小和修改时间，文件不存在时返回 None
+    fn new(server: &str, user: &str, path: &Path) -> Option<Self> {
+        let metadata = fs::metadata(path).ok()?;
+        let modified = metadata
+            .modified()
+            .ok()?
+            .duration_since(UNIX_EPOCH)
+            .ok()?
+            .as_millis() as i64;
+        Some(Self {
+            server: server.to_string(),
+            user: user.to_string(),
+            path: path.to_path_buf(),
+            size: metadata.len(),
+            modified,
+        })
+    }
+}
+
+/// 记录文件中的一行
+#[derive(Debug, Serialize, Deserialize)]
+struct LedgerRecord {
+    #[serde(flatten)]
+    key: LedgerKey,
+    /// 服务器上的资源 ID
+    asset_id: String,
+}
+
+/// 本地记录的已上传文件，无需计算校验和或请求服务器即可跳过
+#[derive(Debug, Default)]
+pub struct UploadLedger {
+    assets: HashMap<LedgerKey, String>,
+}
+
+/// 记录文件路径 (~/.immich/uploaded.jsonl)
+fn ledger_path() -> Result<PathBuf> {
+    let home = dirs::home_dir().context("Failed to get home directory")?;
+    Ok(home.join(".immich").join("uploaded.jsonl"))
+}
+
+impl UploadLedger {
+    /// 读取本地记录，无法解析的行会被跳过
+    pub fn load() -> Result<Self> {
+        Self::load_from(&ledger_path()?)
+    }
+
+    fn load_from(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let file = fs::File::open(path).context("Failed to open upload ledger")?;
+        let mut assets = HashMap::new();
+        for line in BufReader::new(file).lines() {
+            let line = line.context("Failed to read upload ledger")?;
+            match serde_json::from_str::<LedgerRecord>(&line) {
+                Ok(record) => {
+                    assets.insert(record.key, record.asset_id);
+                }
+                Err(e) if !line.trim().is_empty() => {
+                    tracing::warn!("Skipping invalid upload ledger line: {}", e)
+                }
+                Err(_) => {}
+            }
+        }
+        Ok(Self { assets })
+    }
+
+    /// 查找自上次上传后未被修改的文件，返回其资源 ID
+    pub fn lookup(&self, server: &str, user: &str, path: &Path) -> Option<String> {
+        let key = LedgerKey::new(server, user, path)?;
+        self.assets.get(&key).cloned()
+    }
+
+    /// 记录已上传的文件及其资源 ID
+    pub fn record(
+        &mut self,
+        server: &str,
+        user: &str,
+        uploaded: &[(PathBuf, String)],
+    ) -> Result<()> {
+        self.record_to(&ledger_path()?, server, user, uploaded)
+    }
+
+    fn record_to(
+        &mut self,
+        path: &Path,
+        server: &str,
+        user: &str,
+        uploaded: &[(PathBuf, String)],
+    ) -> Result<()> {
+        let mut content = String::new();
+        for (path, asset_id) in uploaded {
+            let Some(key) = LedgerKey::new(server, user, path) else {
+                continue;
+            };
+            if self.assets.get(&key) == Some(asset_id) {
+                continue;
+            }
+            let record = LedgerRecord {
+                key,
+                asset_id: asset_id.clone(),
+            };
+            content
+                .push_str(&serde_json::to_string(&record).context("Failed to serialize ledger")?);
+            content.push('\n');
+            self.assets.insert(record.key, record.asset_id);
+        }
+        if content.is_empty() {
+            return Ok(());
+        }
+
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).context("Failed to create config directory")?;
+        }
+        OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .and_then(|mut file| file.write_all(content.as_bytes()))
+            .context("Failed to write upload ledger")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::test_util::{set_modified, temp_path};
+    use std::time::{Duration, SystemTime};
+
+    const SERVER: &str = "http://localhost:2283";
+
+    #[test]
+    fn lookup_matches_path_size_mtime_user_and_server() {
+        let ledger_file = temp_path("ledger-uploaded.jsonl");
+        let photo = temp_path("ledger-photo.jpg");
+        let other = temp_path("ledger-other.jpg");
+        fs::write(&photo, b"photo").unwrap();
+        fs::write(&other, b"photo").unwrap();
+
+        let mut ledger = UploadLedger::default();
+        ledger
+            .record_to(
+                &ledger_file,
+                SERVER,
+                "alice",
+                &[(photo.clone(), "asset-1".to_string())],
+            )
+            .unwrap();
+        // 已记录的文件不会重复写入
+        ledger
+            .record_to(
+                &ledger_file,
+                SERVER,
+                "alice",
+                &[(photo.clone(), "asset-1".to_string())],
+            )
+            .unwrap();
+        assert_eq!(fs::read_to_string(&ledger_file).unwrap().lines().count(), 1);
+
+        let ledger = UploadLedger::load_from(&ledger_file).unwrap();
+        assert_eq!(
+            ledger.lookup(SERVER, "alice", &photo).as_deref(),
+            Some("asset-1")
+        );
+        assert_eq!(ledger.lookup(SERVER, "bob", &photo), None);
+        assert_eq!(ledger.lookup("http://other:2283", "alice", &photo), None);
+        // 内容相同但路径不同
+        assert_eq!(ledger.lookup(SERVER, "alice", &other), None);
+
+        // 修改时间变化
+        set_modified(&photo, SystemTime::now() - Duration::from_secs(60));
+        assert_eq!(ledger.lookup(SERVER, "alice", &photo), None);
+
+        // 大小变化
+        fs::write(&photo, b"edited photo").unwrap();
+        assert_eq!(ledger.lookup(SERVER, "alice", &photo), None);
+
+        // 文件已删除
+        fs::remove_file(&photo).unwrap();
+        assert_eq!(ledger.lookup(SERVER, "alice", &photo), None);
+
+        let _ = fs::remove_file(&other);
+        let _ = fs::remove_file(&ledger_file);
+    }
+
+    #[test]
+    fn skips_malformed_lines() {
+        let ledger_file = temp_path("ledger-malformed.jsonl");
+        let photo = temp_path("ledger-malformed.jpg");
+        fs::write(&photo, b"photo").unwrap();
+        fs::write(&ledger_file, "not json\n\n{\"server\": 1}\n").unwrap();
+
+        let mut ledger = UploadLedger::load_from(&ledger_file).unwrap();
+        assert!(ledger.assets.is_empty());
+        ledger
+            .record_to(
+                &ledger_file,
+                SERVER,
+                "alice",
+                &[(photo.clone(), "asset-1".to_string())],
+            )
+            .unwrap();
+
+        let ledger = UploadLedger::load_from(&ledger_file).unwrap();
+        assert_eq!(ledger.assets.len(), 1);
+        assert_eq!(
+            ledger.lookup(SERVER, "alice", &photo).as_deref(),
+            Some("asset-1")
+        );
+
+        let _ = fs::remove_file(&photo);
+        let _ = fs::remove_file(&ledger_file);
+    }
+}
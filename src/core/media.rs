@@ -0,0 +1,389 @@
+use crate::core::metadata::{read_capture_time, read_content_identifier};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::time::UNIX_EPOCH;
+
+/// 支持上传的文件扩展名（小写）
+pub const SUPPORTED_EXTENSIONS: &[&str] = &["jpg", "jpeg", "png", "heic", "webp", "mp4", "mov"];
+
+/// 视频文件扩展名（小写）
+const VIDEO_EXTENSIONS: &[&str] = &["mp4", "mov"];
+
+/// XMP 元数据文件扩展名（小写）
+const SIDECAR_EXTENSION: &str = "xmp";
+
+/// Live Photo 静态图片的扩展名（小写）
+const LIVE_PHOTO_IMAGE_EXTENSIONS: &[&str] = &["heic", "jpg", "jpeg"];
+
+/// Live Photo 视频的扩展名（小写）
+const LIVE_PHOTO_VIDEO_EXTENSION: &str = "mov";
+
+/// 媒体类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MediaType {
+    Image,
+    Video,
+}
+
+/// 文件列表的排序方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortKey {
+    Name,
+    /// 拍摄时间
+    Date,
+    Size,
+    /// 扩展名
+    Type,
+}
+
+/// 选中文件的统计信息
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct MediaSummary {
+    pub count: usize,
+    pub total_bytes: u64,
+    pub images: usize,
+    pub videos: usize,
+}
+
+/// 目录扫描结果
+#[derive(Debug, Default)]
+pub struct MediaScan {
+    /// 找到的可上传文件
+    pub files: Vec<PathBuf>,
+    /// 被忽略的文件数量（不支持的格式、隐藏文件等，不含 XMP 文件）
+    pub ignored: usize,
+}
+
+/// 判断文件扩展名是否受支持
+pub fn is_supported_media(path: &Path) -> bool {
+    path.extension()
+        .and_then(|ext| ext.to_str())
+        .map(|ext| SUPPORTED_EXTENSIONS.contains(&ext.to_ascii_lowercase().as_str()))
+        .unwrap_or(false)
+}
+
+/// 小写的文件扩展名，没有扩展名时为空
+pub fn extension_of(path: &Path) -> String {
+    path.extension()
+        .map(|ext| ext.to_string_lossy().to_ascii_lowercase())
+        .unwrap_or_default()
+}
+
+pub fn media_type(path: &Path) -> MediaType {
+    if VIDEO_EXTENSIONS.contains(&extension_of(path).as_str()) {
+        MediaType::Video
+    } else {
+        MediaType::Image
+    }
+}
+
+/// 查找媒体文件的 XMP 元数据文件，优先 `<name>.<ext>.xmp`，其次 `<name>.xmp`
+pub fn find_sidecar(path: &Path) -> Option<PathBuf> {
+    let mut candidates = Vec::new();
+    for ext in [SIDECAR_EXTENSION, "XMP"] {
+        let mut full_name = path.as_os_str().to_os_string();
+        full_name.push(".");
+        full_name.push(ext);
+        candidates.push(PathBuf::from(full_name));
+    }
+    for ext in [SIDECAR_EXTENSION, "XMP"] {
+        candidates.push(path.with_extension(ext));
+    }
+    candidates.into_iter().find(|candidate| candidate.is_file())
+}
+
+/// 将同一目录下文件名相同的图片和 MOV 视频配对为 Live Photo，返回图片到视频的映射
+///
+/// 两者都能读取到 Apple 内容标识时，只有标识一致才配对；每个视频最多配对一张图片
+pub fn pair_live_photos(files: &[PathBuf]) -> HashMap<PathBuf, PathBuf> {
+    let key_of = |path: &Path| {
+        let stem = path.file_stem()?.to_string_lossy().to_lowercase();
+        Some((path.parent()?.to_path_buf(), stem))
+    };
+    let mut videos: HashMap<_, &PathBuf> = files
+        .iter()
+        .filter(|path| extension_of(path) == LIVE_PHOTO_VIDEO_EXTENSION)
+        .filter_map(|path| Some((key_of(path)?, path)))
+        .collect();
+
+    let mut pairs = HashMap::new();
+    for image in files
+        .iter()
+        .filter(|path| LIVE_PHOTO_IMAGE_EXTENSIONS.contains(&extension_of(path).as_str()))
+    {
+        let Some(key) = key_of(image) else {
+            continue;
+        };
+        let Some(video) = videos.get(&key) else {
+            continue;
+        };
+        if let (Some(image_id), Some(video_id)) = (
+            read_content_identifier(image),
+            read_content_identifier(video),
+        ) && image_id != video_id
+        {
+            tracing::debug!(
+                "Content identifiers of {:?} and {:?} differ, not a Live Photo",
+                image,
+                video
+            );
+            continue;
+        }
+        tracing::debug!("Live Photo pair: {:?} + {:?}", image, video);
+        pairs.insert(image.clone(), (*video).clone());
+        videos.remove(&key);
+    }
+    pairs
+}
+
+/// 统计文件数量、总大小和图片、视频数量，无法读取的文件大小按 0 计算
+pub fn summarize_media(files: &[PathBuf]) -> MediaSummary {
+    let mut summary = MediaSummary {
+        count: files.len(),
+        ..Default::default()
+    };
+    for path in files {
+        summary.total_bytes += std::fs::metadata(path).map(|m| m.len()).unwrap_or(0);
+        match media_type(path) {
+            MediaType::Image => summary.images += 1,
+            MediaType::Video => summary.videos += 1,
+        }
+    }
+    summary
+}
+
+/// 按指定方式排序，相同时按路径排序；按日期排序需要读取每个文件的拍摄时间
+pub fn sort_media_files(files: &mut [PathBuf], key: SortKey) {
+    match key {
+        SortKey::Name => files.sort_by_cached_key(|path| {
+            let name = path
+                .file_name()
+                .map(|name| name.to_string_lossy().to_lowercase())
+                .unwrap_or_default();
+            (name, path.clone())
+        }),
+        SortKey::Date => files.sort_by_cached_key(|path| {
+            let modified = std::fs::metadata(path)
+                .and_then(|m| m.modified())
+                .unwrap_or(UNIX_EPOCH);
+            (read_capture_time(path, modified).time, path.clone())
+        }),
+        SortKey::Size => files.sort_by_cached_key(|path| {
+            let size = std::fs::metadata(path).map(|m| m.len()).unwrap_or(0);
+            (size, path.clone())
+        }),
+        SortKey::Type => files.sort_by_cached_key(|path| (extension_of(path), path.clone())),
+    }
+}
+
+/// 判断是否为隐藏文件或系统文件
+fn is_hidden(path: &Path) -> bool {
+    path.file_name()
+        .and_then(|name| name.to_str())
+        .map(|name| {
+            name.starts_with('.')
+                || name.eq_ignore_ascii_case("Thumbs.db")
+                || name.eq_ignore_ascii_case("desktop.ini")
+                || name.starts_with("@eaDir")
+        })
+        .unwrap_or(false)
+}
+
+/// 收集给定路径下的所有媒体文件，目录会被递归展开
+///
+/// 显式给出的路径总会被处理；目录内的隐藏文件会被忽略，符号链接不会被跟随，避免目录循环
+pub fn collect_media_files(paths: &[PathBuf]) -> MediaScan {
+    let mut scan = MediaScan::default();
+    for path in paths {
+        visit(path, true, &mut scan);
+    }
+    scan.files.sort();
+    scan.files.dedup();
+    tracing::debug!(
+        "Media scan finished: {} files found, {} ignored",
+        scan.files.len(),
+        scan.ignored
+    );
+    scan
+}
+
+fn visit(path: &Path, is_root: bool, scan: &mut MediaScan) {
+    if !is_root && is_hidden(path) {
+        scan.ignored += 1;
+        return;
+    }
+
+    let metadata = if is_root {
+        std::fs::metadata(path)
+    } else {
+        std::fs::symlink_metadata(path)
+    };
+    let file_type = match metadata {
+        Ok(metadata) => metadata.file_type(),
+        Err(e) => {
+            tracing::warn!("Failed to read metadata for {:?}: {}", path, e);
+            scan.ignored += 1;
+            return;
+        }
+    };
+
+    if file_type.is_dir() {
+        let entries = match std::fs::read_dir(path) {
+            Ok(entries) => entries,
+            Err(e) => {
+                tracing::warn!("Failed to read directory {:?}: {}", path, e);
+                return;
+            }
+        };
+        for entry in entries.flatten() {
+            visit(&entry.path(), false, scan);
+        }
+    } else if file_type.is_file() && is_supported_media(path) {
+        scan.files.push(path.to_path_buf());
+    } else if file_type.is_file() && extension_of(path) == SIDECAR_EXTENSION {
+        // XMP 文件随对应的媒体文件一起上传，不计入忽略数量
+    } else {
+        scan.ignored += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::test_util::{temp_dir, write_file};
+
+    #[test]
+    fn sidecar_prefers_full_name() {
+        let dir = temp_dir("media-sidecar");
+        let photo = write_file(&dir.join("photo.jpg"), b"jpg");
+        assert_eq!(find_sidecar(&photo), None);
+
+        let short = write_file(&dir.join("photo.xmp"), b"xmp");
+        assert_eq!(find_sidecar(&photo), Some(short));
+
+        let full = write_file(&dir.join("photo.jpg.xmp"), b"xmp");
+        assert_eq!(find_sidecar(&photo), Some(full));
+
+        // 其他文件的 XMP 不会被关联
+        let other = write_file(&dir.join("other.png"), b"png");
+        assert_eq!(find_sidecar(&other), None);
+    }
+
+    #[test]
+    fn scan_leaves_sidecars_out_of_counts() {
+        let dir = temp_dir("media-scan-sidecar");
+        let photo = write_file(&dir.join("photo.jpg"), b"jpg");
+        write_file(&dir.join("photo.jpg.xmp"), b"xmp");
+        write_file(&dir.join("video.xmp"), b"xmp");
+        write_file(&dir.join("notes.txt"), b"txt");
+
+        let scan = collect_media_files(std::slice::from_ref(&dir));
+        assert_eq!(scan.files, [photo]);
+        assert_eq!(scan.ignored, 1);
+    }
+
+    #[test]
+    fn scan_skips_hidden_and_system_files() {
+        let dir = temp_dir("media-scan-hidden");
+        let photo = write_file(&dir.join("a.jpg"), b"jpg");
+        let nested = write_file(&dir.join("sub").join("b.MP4"), b"mp4");
+        write_file(&dir.join(".hidden.jpg"), b"jpg");
+        write_file(&dir.join(".git").join("c.jpg"), b"jpg");
+        write_file(&dir.join("@eaDir").join("d.jpg"), b"jpg");
+        write_file(&dir.join("Thumbs.db"), b"db");
+
+        let scan = collect_media_files(std::slice::from_ref(&dir));
+        assert_eq!(scan.files, [photo, nested]);
+        assert_eq!(scan.ignored, 4);
+
+        // 显式选择的隐藏文件仍会上传
+        let hidden = dir.join(".hidden.jpg");
+        let scan = collect_media_files(std::slice::from_ref(&hidden));
+        assert_eq!(scan.files, [hidden]);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn scan_does_not_follow_symlinks_inside_folders() {
+        use std::os::unix::fs::symlink;
+
+        let dir = temp_dir("media-scan-symlink");
+        let photo = write_file(&dir.join("real").join("a.jpg"), b"jpg");
+        symlink(&photo, dir.join("link.jpg")).unwrap();
+        // 指向上级目录的链接会造成循环
+        symlink(&dir, dir.join("real").join("loop")).unwrap();
+
+        let scan = collect_media_files(std::slice::from_ref(&dir));
+        assert_eq!(scan.files, [photo]);
+        assert_eq!(scan.ignored, 2);
+
+        // 显式选择的链接会被跟随
+        let link = dir.join("link.jpg");
+        let scan = collect_media_files(std::slice::from_ref(&link));
+        assert_eq!(scan.files, [link]);
+    }
+
+    #[test]
+    fn summary_counts_types_and_bytes() {
+        let dir = temp_dir("media-summary");
+        let files = [
+            write_file(&dir.join("a.jpg"), vec![0; 100]),
+            write_file(&dir.join("b.HEIC"), vec![0; 50]),
+            write_file(&dir.join("c.MOV"), vec![0; 1000]),
+            dir.join("missing.mp4"),
+        ];
+
+        assert_eq!(
+            summarize_media(&files),
+            MediaSummary {
+                count: 4,
+                total_bytes: 1150,
+                images: 2,
+                videos: 2,
+            }
+        );
+        assert_eq!(summarize_media(&[]), MediaSummary::default());
+    }
+
+    #[test]
+    fn pairs_live_photos() {
+        let dir = temp_dir("media-live-photos");
+        let file = |name: &str| write_file(&dir.join(name), b"data");
+        let still = file("IMG_0001.HEIC");
+        let video = file("IMG_0001.mov");
+        let lone_still = file("IMG_0002.jpg");
+        let lone_video = file("IMG_0003.MOV");
+        let jpeg = file("IMG_0004.JPEG");
+        let jpeg_video = file("img_0004.Mov");
+        // 不同目录中的同名文件不配对
+        let other_dir_video = write_file(&dir.join("other").join("IMG_0002.MOV"), b"data");
+        let mp4 = file("IMG_0005.mp4");
+        let mp4_still = file("IMG_0005.jpg");
+
+        let pairs = pair_live_photos(&[
+            still.clone(),
+            video.clone(),
+            lone_still,
+            lone_video,
+            jpeg.clone(),
+            jpeg_video.clone(),
+            other_dir_video,
+            mp4,
+            mp4_still,
+        ]);
+        assert_eq!(pairs, HashMap::from([(still, video), (jpeg, jpeg_video)]));
+    }
+
+    #[test]
+    fn pairs_each_video_once() {
+        let dir = temp_dir("media-live-photo-once");
+        let heic = write_file(&dir.join("IMG_0001.heic"), b"data");
+        let jpg = write_file(&dir.join("IMG_0001.jpg"), b"data");
+        let video = write_file(&dir.join("IMG_0001.mov"), b"data");
+
+        let pairs = pair_live_photos(&[heic.clone(), jpg.clone(), video.clone()]);
+        assert_eq!(pairs.len(), 1);
+        assert_eq!(pairs.values().next(), Some(&video));
+        assert!(pairs.contains_key(&heic) || pairs.contains_key(&jpg));
+    }
+}
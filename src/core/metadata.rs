@@ -0,0 +1,544 @@
+use chrono::{DateTime, FixedOffset, Local, NaiveDate, NaiveDateTime, TimeZone, Utc};
+use regex::Regex;
+use std::fmt;
+use std::fs::File;
+use std::io::{BufReader, Read, Seek, SeekFrom};
+use std::path::Path;
+use std::sync::LazyLock;
+use std::time::SystemTime;
+
+/// 文件名中的日期时间，例如 IMG_20240101_120000、PXL_20240101_120000123、20240101-120000
+static FILE_NAME_DATE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(concat!(
+        r"(?:^|\D)((?:19|20)\d{2})(\d{2})(\d{2})",
+        r"[_\-\s]?(\d{2})(\d{2})(\d{2})",
+        r"(?:\D|\d{3}(?:\D|$)|$)",
+    ))
+    .expect("valid file name date regex")
+});
+
+/// QuickTime/MP4 时间戳的起点 1904-01-01 与 Unix 纪元之间的秒数
+const MP4_EPOCH_OFFSET: i64 = 2_082_844_800;
+
+/// Apple MakerNote 中 ContentIdentifier 的标签号
+const APPLE_CONTENT_IDENTIFIER_TAG: u16 = 0x0011;
+
+/// QuickTime 元数据中 Live Photo 内容标识的键名
+const QUICKTIME_CONTENT_IDENTIFIER: &[u8] = b"com.apple.quicktime.content.identifier";
+
+/// 读取的元数据 box 的最大长度，避免异常文件占用过多内存
+const MAX_METADATA_BOX_SIZE: u64 = 1024 * 1024;
+
+/// 拍摄时间的来源
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CaptureSource {
+    /// 图片 EXIF 中的 DateTimeOriginal
+    Exif,
+    /// 视频 mvhd 中的创建时间
+    Video,
+    /// 文件名中的日期
+    FileName,
+    /// 文件修改时间
+    Modified,
+}
+
+impl fmt::Display for CaptureSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            CaptureSource::Exif => "EXIF",
+            CaptureSource::Video => "video metadata",
+            CaptureSource::FileName => "file name",
+            CaptureSource::Modified => "file modified time",
+        };
+        f.write_str(name)
+    }
+}
+
+/// 文件的拍摄时间
+#[derive(Debug, Clone, Copy)]
+pub struct CaptureTime {
+    pub time: DateTime<Utc>,
+    pub source: CaptureSource,
+}
+
+/// 读取文件的拍摄时间，依次尝试 EXIF、视频元数据和文件名，都没有时使用修改时间
+pub fn read_capture_time(path: &Path, modified: SystemTime) -> CaptureTime {
+    let detected = read_exif_time(path)
+        .map(|time| (time, CaptureSource::Exif))
+        .or_else(|| read_video_time(path).map(|time| (time, CaptureSource::Video)))
+        .or_else(|| parse_file_name_time(path).map(|time| (time, CaptureSource::FileName)));
+
+    let (time, source) = detected.unwrap_or((modified.into(), CaptureSource::Modified));
+    tracing::debug!("Capture time for {:?}: {} (from {})", path, time, source);
+    CaptureTime { time, source }
+}
+
+fn read_exif_time(path: &Path) -> Option<DateTime<Utc>> {
+    let file = File::open(path).ok()?;
+    let exif = exif::Reader::new()
+        .read_from_container(&mut BufReader::new(file))
+        .ok()?;
+
+    let ascii = |tag| match exif
+        .get_field(tag, exif::In::PRIMARY)
+        .map(|field| &field.value)
+    {
+        Some(exif::Value::Ascii(values)) => values.first().cloned(),
+        _ => None,
+    };
+    let mut date_time = [exif::Tag::DateTimeOriginal, exif::Tag::DateTimeDigitized]
+        .into_iter()
+        .filter_map(ascii)
+        .find_map(|value| exif::DateTime::from_ascii(&value).ok())?;
+    if let Some(offset) = ascii(exif::Tag::OffsetTimeOriginal) {
+        let _ = date_time.parse_offset(&offset);
+    }
+
+    let naive = NaiveDate::from_ymd_opt(
+        date_time.year as i32,
+        date_time.month as u32,
+        date_time.day as u32,
+    )?
+    .and_hms_opt(
+        date_time.hour as u32,
+        date_time.minute as u32,
+        date_time.second as u32,
+    )?;
+    match date_time.offset {
+        Some(minutes) => FixedOffset::east_opt(minutes as i32 * 60)?
+            .from_local_datetime(&naive)
+            .single()
+            .map(|time| time.with_timezone(&Utc)),
+        // 没有时区信息时按本地时间处理
+        None => local_to_utc(naive),
+    }
+}
+
+/// 从 MP4/MOV 的 moov/mvhd 中读取创建时间
+fn read_video_time(path: &Path) -> Option<DateTime<Utc>> {
+    let mut file = File::open(path).ok()?;
+    let file_len = file.metadata().ok()?.len();
+    parse_video_time(&mut file, file_len)
+}
+
+fn parse_video_time<R: Read + Seek>(reader: &mut R, len: u64) -> Option<DateTime<Utc>> {
+    let (moov_start, moov_end) = find_box(reader, 0, len, b"moov")?;
+    let (mvhd_start, _) = find_box(reader, moov_start, moov_end, b"mvhd")?;
+
+    reader.seek(SeekFrom::Start(mvhd_start)).ok()?;
+    let mut version = [0; 4];
+    reader.read_exact(&mut version).ok()?;
+    let seconds = if version[0] == 1 {
+        let mut buffer = [0; 8];
+        reader.read_exact(&mut buffer).ok()?;
+        u64::from_be_bytes(buffer)
+    } else {
+        let mut buffer = [0; 4];
+        reader.read_exact(&mut buffer).ok()?;
+        u32::from_be_bytes(buffer) as u64
+    };
+    // 很多设备不写入创建时间，此时为 0
+    if seconds == 0 {
+        return None;
+    }
+    Utc.timestamp_opt(
+        i64::try_from(seconds).ok()?.checked_sub(MP4_EPOCH_OFFSET)?,
+        0,
+    )
+    .single()
+}
+
+/// 读取 Apple Live Photo 的内容标识，图片和对应的视频相同
+///
+/// 图片从 EXIF MakerNote 读取，视频从 QuickTime 的 moov/meta 元数据读取
+pub fn read_content_identifier(path: &Path) -> Option<String> {
+    read_image_content_identifier(path).or_else(|| read_video_content_identifier(path))
+}
+
+fn read_image_content_identifier(path: &Path) -> Option<String> {
+    let file = File::open(path).ok()?;
+    let exif = exif::Reader::new()
+        .read_from_container(&mut BufReader::new(file))
+        .ok()?;
+    match &exif
+        .get_field(exif::Tag::MakerNote, exif::In::PRIMARY)?
+        .value
+    {
+        exif::Value::Undefined(data, _) => parse_apple_maker_note(data),
+        _ => None,
+    }
+}
+
+/// Apple MakerNote 以 "Apple iOS\0"、版本号和字节序标记开头，之后是 IFD，
+/// 偏移量相对于 MakerNote 的起始位置
+fn parse_apple_maker_note(data: &[u8]) -> Option<String> {
+    if !data.starts_with(b"Apple iOS\0") {
+        return None;
+    }
+    let big_endian = match data.get(12..14)? {
+        b"MM" => true,
+        b"II" => false,
+        _ => return None,
+    };
+    let u16_at = |offset: usize| {
+        let bytes = data.get(offset..offset + 2)?.try_into().ok()?;
+        Some(if big_endian {
+            u16::from_be_bytes(bytes)
+        } else {
+            u16::from_le_bytes(bytes)
+        })
+    };
+    let u32_at = |offset: usize| {
+        let bytes = data.get(offset..offset + 4)?.try_into().ok()?;
+        Some(if big_endian {
+            u32::from_be_bytes(bytes)
+        } else {
+            u32::from_le_bytes(bytes)
+        })
+    };
+
+    let count = u16_at(14)? as usize;
+    let entry = (0..count)
+        .map(|index| 16 + index * 12)
+        .find(|&entry| u16_at(entry) == Some(APPLE_CONTENT_IDENTIFIER_TAG))?;
+    let len = u32_at(entry + 4)? as usize;
+    // 不超过 4 字节的值直接存放在条目中
+    let value = if len <= 4 {
+        data.get(entry + 8..entry + 8 + len)?
+    } else {
+        let offset = u32_at(entry + 8)? as usize;
+        data.get(offset..offset.checked_add(len)?)?
+    };
+    non_empty_text(value)
+}
+
+/// QuickTime 元数据：keys 中按顺序列出键名，ilst 中以键的序号（从 1 开始）作为 box 类型保存值
+fn read_video_content_identifier(path: &Path) -> Option<String> {
+    let mut file = File::open(path).ok()?;
+    let file_len = file.metadata().ok()?.len();
+
+    let (moov_start, moov_end) = find_box(&mut file, 0, file_len, b"moov")?;
+    let (meta_start, meta_end) = find_box(&mut file, moov_start, moov_end, b"meta")?;
+    // QuickTime 的 meta 没有版本和标志字段，ISO 格式的 meta 有
+    let (keys_start, keys_end, children_start) =
+        [meta_start, meta_start + 4].into_iter().find_map(|start| {
+            find_box(&mut file, start, meta_end, b"keys").map(|(s, e)| (s, e, start))
+        })?;
+    let keys = read_box(&mut file, keys_start, keys_end)?;
+
+    // 跳过版本、标志和条目数
+    let mut offset = 8;
+    let mut index = 0u32;
+    let key_index = loop {
+        let size = u32::from_be_bytes(keys.get(offset..offset + 4)?.try_into().ok()?) as usize;
+        if size < 8 {
+            return None;
+        }
+        index += 1;
+        if keys.get(offset + 8..offset + size)? == QUICKTIME_CONTENT_IDENTIFIER {
+            break index;
+        }
+        offset += size;
+    };
+
+    let (ilst_start, ilst_end) = find_box(&mut file, children_start, meta_end, b"ilst")?;
+    let (item_start, item_end) =
+        find_box(&mut file, ilst_start, ilst_end, &key_index.to_be_bytes())?;
+    let (data_start, data_end) = find_box(&mut file, item_start, item_end, b"data")?;
+    let data = read_box(&mut file, data_start, data_end)?;
+    // 跳过类型和语言字段
+    non_empty_text(data.get(8..)?)
+}
+
+fn read_box<R: Read + Seek>(reader: &mut R, start: u64, end: u64) -> Option<Vec<u8>> {
+    let len = end.checked_sub(start)?;
+    if len > MAX_METADATA_BOX_SIZE {
+        return None;
+    }
+    reader.seek(SeekFrom::Start(start)).ok()?;
+    let mut buffer = vec![0; len as usize];
+    reader.read_exact(&mut buffer).ok()?;
+    Some(buffer)
+}
+
+fn non_empty_text(value: &[u8]) -> Option<String> {
+    let text = String::from_utf8_lossy(value)
+        .trim_end_matches('\0')
+        .trim()
+        .to_string();
+    (!text.is_empty()).then_some(text)
+}
+
+/// 在 [start, end) 范围内查找指定类型的 box，返回其内容的起止位置
+fn find_box<R: Read + Seek>(
+    reader: &mut R,
+    start: u64,
+    end: u64,
+    box_type: &[u8; 4],
+) -> Option<(u64, u64)> {
+    // box 大小来自文件内容，异常文件中可能接近 u64::MAX，所有偏移计算都需检查溢出
+    let mut offset = start;
+    while offset.checked_add(8)? <= end {
+        reader.seek(SeekFrom::Start(offset)).ok()?;
+        let mut header = [0; 8];
+        reader.read_exact(&mut header).ok()?;
+        let mut size = u32::from_be_bytes([header[0], header[1], header[2], header[3]]) as u64;
+        let mut header_len = 8;
+        if size == 1 {
+            let mut large_size = [0; 8];
+            reader.read_exact(&mut large_size).ok()?;
+            size = u64::from_be_bytes(large_size);
+            header_len = 16;
+        } else if size == 0 {
+            // 大小为 0 表示延伸到范围末尾
+            size = end.checked_sub(offset)?;
+        }
+        if size < header_len {
+            return None;
+        }
+        let box_end = offset.checked_add(size)?;
+        if &header[4..8] == box_type {
+            return Some((offset + header_len, box_end.min(end)));
+        }
+        offset = box_end;
+    }
+    None
+}
+
+fn parse_file_name_time(path: &Path) -> Option<DateTime<Utc>> {
+    let stem = path.file_stem()?.to_str()?;
+    let captures = FILE_NAME_DATE.captures(stem)?;
+    let number = |index| captures.get(index)?.as_str().parse::<u32>().ok();
+
+    let date = NaiveDate::from_ymd_opt(number(1)? as i32, number(2)?, number(3)?)?;
+    let naive = date.and_hms_opt(number(4)?, number(5)?, number(6)?)?;
+    local_to_utc(naive)
+}
+
+fn local_to_utc(naive: NaiveDateTime) -> Option<DateTime<Utc>> {
+    Local
+        .from_local_datetime(&naive)
+        .earliest()
+        .map(|time| time.with_timezone(&Utc))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    fn mp4_box(box_type: &[u8; 4], payload: &[u8]) -> Vec<u8> {
+        let mut data = ((payload.len() + 8) as u32).to_be_bytes().to_vec();
+        data.extend_from_slice(box_type);
+        data.extend_from_slice(payload);
+        data
+    }
+
+    fn video_time(data: Vec<u8>) -> Option<DateTime<Utc>> {
+        let len = data.len() as u64;
+        parse_video_time(&mut Cursor::new(data), len)
+    }
+
+    fn mvhd_v0(seconds: u32) -> Vec<u8> {
+        let mut payload = vec![0; 4];
+        payload.extend_from_slice(&seconds.to_be_bytes());
+        mp4_box(b"moov", &mp4_box(b"mvhd", &payload))
+    }
+
+    fn mvhd_v1(seconds: u64) -> Vec<u8> {
+        let mut payload = vec![1, 0, 0, 0];
+        payload.extend_from_slice(&seconds.to_be_bytes());
+        mp4_box(b"moov", &mp4_box(b"mvhd", &payload))
+    }
+
+    #[test]
+    fn find_box_skips_to_matching_type() {
+        let mut data = mp4_box(b"ftyp", b"isom");
+        data.extend(mp4_box(b"moov", b"abcd"));
+        let len = data.len() as u64;
+
+        let found = find_box(&mut Cursor::new(data), 0, len, b"moov");
+
+        assert_eq!(found, Some((20, 24)));
+    }
+
+    #[test]
+    fn find_box_reads_large_size_header() {
+        let mut data = 1u32.to_be_bytes().to_vec();
+        data.extend_from_slice(b"moov");
+        data.extend_from_slice(&20u64.to_be_bytes());
+        data.extend_from_slice(b"abcd");
+        let len = data.len() as u64;
+
+        let found = find_box(&mut Cursor::new(data), 0, len, b"moov");
+
+        assert_eq!(found, Some((16, 20)));
+    }
+
+    #[test]
+    fn find_box_clamps_truncated_box_to_range() {
+        // 声明 100 字节，实际只有 12 字节
+        let mut data = 100u32.to_be_bytes().to_vec();
+        data.extend_from_slice(b"moov");
+        data.extend_from_slice(b"abcd");
+
+        let found = find_box(&mut Cursor::new(data), 0, 12, b"moov");
+
+        assert_eq!(found, Some((8, 12)));
+    }
+
+    #[test]
+    fn find_box_rejects_out_of_range_sizes() {
+        // 大小小于头部长度
+        let mut too_small = 4u32.to_be_bytes().to_vec();
+        too_small.extend_from_slice(b"free");
+        assert_eq!(find_box(&mut Cursor::new(too_small), 0, 8, b"moov"), None);
+
+        // 64 位大小接近 u64::MAX，计算下一个 box 的位置时溢出
+        let mut data = mp4_box(b"ftyp", b"isom");
+        data.extend_from_slice(&1u32.to_be_bytes());
+        data.extend_from_slice(b"free");
+        data.extend_from_slice(&u64::MAX.to_be_bytes());
+        data.extend(mp4_box(b"moov", b""));
+        let len = data.len() as u64;
+        assert_eq!(find_box(&mut Cursor::new(data), 0, len, b"moov"), None);
+
+        // 头部被截断
+        let truncated = vec![0, 0, 0];
+        assert_eq!(find_box(&mut Cursor::new(truncated), 0, 3, b"moov"), None);
+    }
+
+    #[test]
+    fn mvhd_version_0_time() {
+        let expected = Utc.with_ymd_and_hms(2024, 1, 1, 12, 0, 0).unwrap();
+        let seconds = (expected.timestamp() + MP4_EPOCH_OFFSET) as u32;
+
+        assert_eq!(video_time(mvhd_v0(seconds)), Some(expected));
+    }
+
+    #[test]
+    fn mvhd_version_1_time() {
+        let expected = Utc.with_ymd_and_hms(2024, 1, 1, 12, 0, 0).unwrap();
+        let seconds = (expected.timestamp() + MP4_EPOCH_OFFSET) as u64;
+
+        assert_eq!(video_time(mvhd_v1(seconds)), Some(expected));
+    }
+
+    #[test]
+    fn mvhd_without_valid_time() {
+        // 未写入创建时间
+        assert_eq!(video_time(mvhd_v0(0)), None);
+        // 超出 i64 范围
+        assert_eq!(video_time(mvhd_v1(u64::MAX)), None);
+        // mvhd 内容被截断
+        let truncated = mp4_box(b"moov", &mp4_box(b"mvhd", &[1, 0, 0, 0, 0, 0]));
+        assert_eq!(video_time(truncated), None);
+        // 没有 moov
+        assert_eq!(video_time(mp4_box(b"ftyp", b"isom")), None);
+    }
+
+    #[test]
+    fn file_name_dates() {
+        let expected = local_to_utc(
+            NaiveDate::from_ymd_opt(2024, 1, 1)
+                .unwrap()
+                .and_hms_opt(12, 30, 45)
+                .unwrap(),
+        );
+        for name in [
+            "IMG_20240101_123045.jpg",
+            "PXL_20240101_123045123.jpg",
+            "20240101-123045.mp4",
+            "VID 20240101 123045.mov",
+        ] {
+            assert_eq!(parse_file_name_time(Path::new(name)), expected, "{name}");
+        }
+    }
+
+    #[test]
+    fn file_name_without_date() {
+        for name in [
+            "IMG_1234.jpg",
+            // 月份无效
+            "IMG_20241301_123045.jpg",
+            // 时间后多出一位数字
+            "IMG_20240101_1230451.jpg",
+            // 日期前紧跟数字
+            "120240101_123045.jpg",
+        ] {
+            assert_eq!(parse_file_name_time(Path::new(name)), None, "{name}");
+        }
+    }
+
+    /// 只包含一个条目的 MakerNote，值的位置紧跟在条目之后
+    fn maker_note(big_endian: bool, tag: u16, value: &[u8]) -> Vec<u8> {
+        let u16_bytes = |value: u16| {
+            if big_endian {
+                value.to_be_bytes()
+            } else {
+                value.to_le_bytes()
+            }
+        };
+        let u32_bytes = |value: u32| {
+            if big_endian {
+                value.to_be_bytes()
+            } else {
+                value.to_le_bytes()
+            }
+        };
+        let mut data = b"Apple iOS\0\0\x01".to_vec();
+        data.extend_from_slice(if big_endian { b"MM" } else { b"II" });
+        data.extend_from_slice(&u16_bytes(1));
+        data.extend_from_slice(&u16_bytes(tag));
+        data.extend_from_slice(&u16_bytes(2));
+        data.extend_from_slice(&u32_bytes(value.len() as u32));
+        if value.len() <= 4 {
+            let mut inline = value.to_vec();
+            inline.resize(4, 0);
+            data.extend_from_slice(&inline);
+        } else {
+            data.extend_from_slice(&u32_bytes(28));
+            data.extend_from_slice(value);
+        }
+        data
+    }
+
+    #[test]
+    fn apple_maker_note_content_identifier() {
+        let id = b"1D3E5F7A-0000-4B2C-9D8E-ABCDEF012345\0";
+        for big_endian in [true, false] {
+            let data = maker_note(big_endian, APPLE_CONTENT_IDENTIFIER_TAG, id);
+            assert_eq!(
+                parse_apple_maker_note(&data).as_deref(),
+                Some("1D3E5F7A-0000-4B2C-9D8E-ABCDEF012345")
+            );
+        }
+        // 不超过 4 字节的值存放在条目中
+        let data = maker_note(true, APPLE_CONTENT_IDENTIFIER_TAG, b"abc");
+        assert_eq!(parse_apple_maker_note(&data).as_deref(), Some("abc"));
+    }
+
+    #[test]
+    fn apple_maker_note_rejects_malformed_data() {
+        let id = b"1D3E5F7A-0000-4B2C-9D8E-ABCDEF012345";
+        // 不是 Apple MakerNote
+        let mut other = maker_note(true, APPLE_CONTENT_IDENTIFIER_TAG, id);
+        other[..5].copy_from_slice(b"Nikon");
+        assert_eq!(parse_apple_maker_note(&other), None);
+        // 没有对应标签
+        assert_eq!(parse_apple_maker_note(&maker_note(true, 0x0001, id)), None);
+        // 值被截断
+        let data = maker_note(true, APPLE_CONTENT_IDENTIFIER_TAG, id);
+        assert_eq!(parse_apple_maker_note(&data[..data.len() - 4]), None);
+        // 条目被截断
+        assert_eq!(parse_apple_maker_note(&data[..20]), None);
+        // 值的长度和偏移超出范围
+        let mut huge = data.clone();
+        huge[20..24].copy_from_slice(&u32::MAX.to_be_bytes());
+        huge[24..28].copy_from_slice(&u32::MAX.to_be_bytes());
+        assert_eq!(parse_apple_maker_note(&huge), None);
+        // 空值
+        let empty = maker_note(true, APPLE_CONTENT_IDENTIFIER_TAG, b"\0\0\0\0\0\0");
+        assert_eq!(parse_apple_maker_note(&empty), None);
+    }
+}
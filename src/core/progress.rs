@@ -0,0 +1,174 @@
+use crate::core::batch::UploadEvent;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+/// 单个文件的上传进度
+#[derive(Debug, Clone, PartialEq)]
+pub struct FileProgress {
+    pub file_name: String,
+    pub sent: u64,
+    pub total: u64,
+}
+
+impl FileProgress {
+    /// 完成比例，取值 0.0 - 1.0
+    pub fn fraction(&self) -> f64 {
+        if self.total == 0 {
+            1.0
+        } else {
+            (self.sent as f64 / self.total as f64).min(1.0)
+        }
+    }
+}
+
+/// 整个批次的字节级上传进度，由 [`UploadEvent`] 驱动更新
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct BatchProgress {
+    /// 待上传文件的总字节数
+    pub total_bytes: u64,
+    /// 已完成文件的字节数
+    pub finished_bytes: u64,
+    /// 正在上传的文件
+    pub active: BTreeMap<PathBuf, FileProgress>,
+    started_at: Option<Instant>,
+}
+
+impl BatchProgress {
+    /// 根据上传事件更新进度
+    pub fn apply(&mut self, event: &UploadEvent) {
+        match event {
+            UploadEvent::Started { total_bytes, .. } => {
+                *self = Self {
+                    total_bytes: *total_bytes,
+                    started_at: Some(Instant::now()),
+                    ..Default::default()
+                };
+            }
+            UploadEvent::Progress {
+                path,
+                file_name,
+                sent,
+                total,
+            } => {
+                let entry = self
+                    .active
+                    .entry(path.clone())
+                    .or_insert_with(|| FileProgress {
+                        file_name: file_name.clone(),
+                        sent: 0,
+                        total: *total,
+                    });
+                entry.sent = *sent;
+                entry.total = *total;
+            }
+            UploadEvent::Uploaded { path, .. } => {
+                self.finished_bytes += self.take_size(path);
+            }
+            // 开始发送前就失败的文件没有进度记录，同样需要从总字节数中扣除
+            UploadEvent::Failed { path, .. } => {
+                self.total_bytes = self.total_bytes.saturating_sub(self.take_size(path));
+            }
+            UploadEvent::Cancelled { paths } => {
+                for path in paths {
+                    self.active.remove(path);
+                }
+            }
+            UploadEvent::CheckingDuplicates { .. } | UploadEvent::Skipped { .. } => {}
+        }
+    }
+
+    /// 移除文件的进度记录并返回其大小，没有记录时读取文件大小
+    fn take_size(&mut self, path: &Path) -> u64 {
+        match self.active.remove(path) {
+            Some(file) => file.total,
+            None => std::fs::metadata(path).map(|m| m.len()).unwrap_or(0),
+        }
+    }
+
+    /// 已发送的字节数
+    pub fn sent_bytes(&self) -> u64 {
+        self.finished_bytes + self.active.values().map(|file| file.sent).sum::<u64>()
+    }
+
+    /// 完成比例，取值 0.0 - 1.0
+    pub fn fraction(&self) -> f64 {
+        if self.total_bytes == 0 {
+            0.0
+        } else {
+            (self.sent_bytes() as f64 / self.total_bytes as f64).min(1.0)
+        }
+    }
+
+    /// 平均吞吐量（字节/秒）
+    pub fn throughput(&self) -> f64 {
+        let elapsed = self
+            .started_at
+            .map(|started_at| started_at.elapsed().as_secs_f64())
+            .unwrap_or_default();
+        if elapsed <= 0.0 {
+            0.0
+        } else {
+            self.sent_bytes() as f64 / elapsed
+        }
+    }
+
+    /// 预计剩余时间，吞吐量未知时返回 None
+    pub fn eta(&self) -> Option<Duration> {
+        let throughput = self.throughput();
+        if throughput <= 0.0 {
+            return None;
+        }
+        let remaining = self.total_bytes.saturating_sub(self.sent_bytes());
+        Some(Duration::from_secs_f64(remaining as f64 / throughput))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::UploadError;
+    use crate::core::test_util::{temp_path, write_file};
+
+    fn temp_file(name: &str, size: usize) -> PathBuf {
+        write_file(&temp_path(&format!("progress-{}", name)), vec![0; size])
+    }
+
+    #[test]
+    fn failure_before_progress_reaches_completion() {
+        let uploaded = temp_file("uploaded.jpg", 300);
+        let failed = temp_file("failed.jpg", 700);
+        let mut progress = BatchProgress::default();
+        progress.apply(&UploadEvent::Started {
+            total: 2,
+            skipped: 0,
+            already_uploaded: 0,
+            total_bytes: 1000,
+        });
+        progress.apply(&UploadEvent::Progress {
+            path: uploaded.clone(),
+            file_name: "uploaded.jpg".to_string(),
+            sent: 300,
+            total: 300,
+        });
+        progress.apply(&UploadEvent::Uploaded {
+            path: uploaded.clone(),
+            file_name: "uploaded.jpg".to_string(),
+            finished: 1,
+            total: 2,
+        });
+        // 没有任何进度事件就失败
+        progress.apply(&UploadEvent::Failed {
+            path: failed.clone(),
+            file_name: "failed.jpg".to_string(),
+            error: UploadError::Other("unauthorized".to_string()),
+        });
+
+        assert_eq!(progress.total_bytes, 300);
+        assert_eq!(progress.fraction(), 1.0);
+        assert_eq!(progress.eta(), Some(Duration::ZERO));
+
+        let _ = std::fs::remove_file(uploaded);
+        let _ = std::fs::remove_file(failed);
+    }
+}
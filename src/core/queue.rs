@@ -0,0 +1,243 @@
+use crate::core::batch::UploadEvent;
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// 队列中单个文件的状态
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum QueueItemState {
+    Pending,
+    Uploading,
+    Done,
+    Failed,
+    /// 本地记录或服务器上已存在
+    Skipped,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct QueueItem {
+    pub path: PathBuf,
+    pub state: QueueItemState,
+}
+
+/// 持久化的上传队列，应用中途退出后可在下次启动时继续上传
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UploadQueue {
+    pub user: String,
+    /// 上传时填写的相册名称
+    #[serde(default)]
+    pub album: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub items: Vec<QueueItem>,
+}
+
+/// 队列文件路径 (~/.immich/queue.json)
+fn queue_path() -> Result<PathBuf> {
+    let home = dirs::home_dir().context("Failed to get home directory")?;
+    Ok(home.join(".immich").join("queue.json"))
+}
+
+impl UploadQueue {
+    pub fn new(user: &str, album: Option<String>, files: &[PathBuf]) -> Self {
+        Self {
+            user: user.to_string(),
+            album,
+            created_at: Utc::now(),
+            items: files
+                .iter()
+                .map(|path| QueueItem {
+                    path: path.clone(),
+                    state: QueueItemState::Pending,
+                })
+                .collect(),
+        }
+    }
+
+    /// 尚未完成的文件：等待上传的和上传到一半被中断的
+    pub fn remaining(&self) -> Vec<PathBuf> {
+        self.items
+            .iter()
+            .filter(|item| {
+                matches!(
+                    item.state,
+                    QueueItemState::Pending | QueueItemState::Uploading
+                )
+            })
+            .map(|item| item.path.clone())
+            .collect()
+    }
+
+    /// 根据上传事件更新文件状态，状态有变化时返回 true
+    pub fn apply(&mut self, event: &UploadEvent) -> bool {
+        match event {
+            UploadEvent::Skipped { paths } => self.set_states(paths, QueueItemState::Skipped),
+            // 取消时未完成的文件恢复为待上传
+            UploadEvent::Cancelled { paths } => self.set_states(paths, QueueItemState::Pending),
+            UploadEvent::Progress { path, .. } => self.set_state(path, QueueItemState::Uploading),
+            UploadEvent::Uploaded { path, .. } => self.set_state(path, QueueItemState::Done),
+            UploadEvent::Failed { path, .. } => self.set_state(path, QueueItemState::Failed),
+            UploadEvent::CheckingDuplicates { .. } | UploadEvent::Started { .. } => false,
+        }
+    }
+
+    fn set_states(&mut self, paths: &[PathBuf], state: QueueItemState) -> bool {
+        let mut changed = false;
+        for path in paths {
+            changed |= self.set_state(path, state);
+        }
+        changed
+    }
+
+    fn set_state(&mut self, path: &PathBuf, state: QueueItemState) -> bool {
+        match self.items.iter_mut().find(|item| item.path == *path) {
+            Some(item) if item.state != state => {
+                item.state = state;
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+/// 读取上次未完成的队列，没有剩余文件时返回 None
+pub fn load_queue() -> Result<Option<UploadQueue>> {
+    read_queue(&queue_path()?)
+}
+
+fn read_queue(path: &Path) -> Result<Option<UploadQueue>> {
+    if !path.exists() {
+        return Ok(None);
+    }
+    let content = fs::read_to_string(path).context("Failed to read upload queue")?;
+    let queue: UploadQueue =
+        serde_json::from_str(&content).context("Failed to parse upload queue")?;
+    Ok(Some(queue).filter(|queue| !queue.remaining().is_empty()))
+}
+
+/// 保存队列，先写入临时文件再替换，避免中途退出时文件损坏
+pub fn save_queue(queue: &UploadQueue) -> Result<()> {
+    write_queue(&queue_path()?, queue)
+}
+
+fn write_queue(path: &Path, queue: &UploadQueue) -> Result<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).context("Failed to create config directory")?;
+    }
+    let content = serde_json::to_string(queue).context("Failed to serialize upload queue")?;
+    let temp_path = path.with_extension("json.tmp");
+    fs::write(&temp_path, content).context("Failed to write upload queue")?;
+    fs::rename(&temp_path, path).context("Failed to replace upload queue")
+}
+
+/// 批次结束或放弃继续上传时删除队列
+pub fn clear_queue() -> Result<()> {
+    let path = queue_path()?;
+    if path.exists() {
+        fs::remove_file(&path).context("Failed to remove upload queue")?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::UploadError;
+    use crate::core::test_util::temp_path;
+
+    fn paths(names: &[&str]) -> Vec<PathBuf> {
+        names.iter().map(PathBuf::from).collect()
+    }
+
+    fn states(queue: &UploadQueue) -> Vec<QueueItemState> {
+        queue.items.iter().map(|item| item.state).collect()
+    }
+
+    #[test]
+    fn apply_updates_states() {
+        use QueueItemState::*;
+        let files = paths(&["a.jpg", "b.jpg", "c.jpg", "d.jpg", "e.jpg"]);
+        let mut queue = UploadQueue::new("alice", None, &files);
+        assert_eq!(queue.remaining(), files);
+
+        assert!(!queue.apply(&UploadEvent::Started {
+            total: 4,
+            skipped: 1,
+            already_uploaded: 0,
+            total_bytes: 100,
+        }));
+        assert!(queue.apply(&UploadEvent::Skipped {
+            paths: paths(&["a.jpg"]),
+        }));
+        let progress = UploadEvent::Progress {
+            path: files[1].clone(),
+            file_name: "b.jpg".to_string(),
+            sent: 10,
+            total: 20,
+        };
+        assert!(queue.apply(&progress));
+        // 同一文件的后续进度不改变状态，无需重新保存队列
+        assert!(!queue.apply(&progress));
+        assert!(queue.apply(&UploadEvent::Uploaded {
+            path: files[1].clone(),
+            file_name: "b.jpg".to_string(),
+            finished: 1,
+            total: 4,
+        }));
+        assert!(queue.apply(&UploadEvent::Failed {
+            path: files[2].clone(),
+            file_name: "c.jpg".to_string(),
+            error: UploadError::Timeout,
+        }));
+        assert!(queue.apply(&UploadEvent::Progress {
+            path: files[3].clone(),
+            file_name: "d.jpg".to_string(),
+            sent: 5,
+            total: 20,
+        }));
+        // 不在队列中的文件被忽略
+        assert!(!queue.apply(&UploadEvent::Skipped {
+            paths: paths(&["z.jpg"]),
+        }));
+
+        assert_eq!(states(&queue), [Skipped, Done, Failed, Uploading, Pending]);
+        // 上传到一半被中断的文件也需要继续上传
+        assert_eq!(queue.remaining(), paths(&["d.jpg", "e.jpg"]));
+
+        assert!(queue.apply(&UploadEvent::Cancelled {
+            paths: paths(&["d.jpg", "e.jpg"]),
+        }));
+        assert_eq!(states(&queue), [Skipped, Done, Failed, Pending, Pending]);
+        assert_eq!(queue.remaining(), paths(&["d.jpg", "e.jpg"]));
+    }
+
+    #[test]
+    fn queue_file_round_trip() {
+        let path = temp_path("queue.json");
+        assert_eq!(read_queue(&path).unwrap(), None);
+
+        let files = paths(&["a.jpg", "b.jpg"]);
+        let mut queue = UploadQueue::new("alice", Some("Trip".to_string()), &files);
+        queue.apply(&UploadEvent::Uploaded {
+            path: files[0].clone(),
+            file_name: "a.jpg".to_string(),
+            finished: 1,
+            total: 2,
+        });
+        write_queue(&path, &queue).unwrap();
+        assert_eq!(read_queue(&path).unwrap(), Some(queue.clone()));
+
+        // 没有剩余文件的队列不再提示继续上传
+        queue.apply(&UploadEvent::Skipped {
+            paths: paths(&["b.jpg"]),
+        });
+        write_queue(&path, &queue).unwrap();
+        assert_eq!(read_queue(&path).unwrap(), None);
+
+        fs::write(&path, "{").unwrap();
+        assert!(read_queue(&path).is_err());
+        let _ = fs::remove_file(&path);
+    }
+}
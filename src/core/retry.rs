@@ -0,0 +1,151 @@
+use reqwest::StatusCode;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// 单次重试等待的最长时间
+const MAX_DELAY: Duration = Duration::from_secs(60);
+
+/// 上传重试策略
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct RetryConfig {
+    /// 最大尝试次数（包含第一次）
+    #[serde(default = "default_max_attempts")]
+    pub max_attempts: u32,
+    /// 第一次重试前的等待时间（毫秒），之后按指数增长
+    #[serde(default = "default_base_delay_ms")]
+    pub base_delay_ms: u64,
+    /// 每次等待额外增加的随机时间上限（毫秒）
+    #[serde(default = "default_jitter_ms")]
+    pub jitter_ms: u64,
+}
+
+fn default_max_attempts() -> u32 {
+    3
+}
+
+fn default_base_delay_ms() -> u64 {
+    1000
+}
+
+fn default_jitter_ms() -> u64 {
+    500
+}
+
+impl Default for RetryConfig {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            base_delay_ms: 1000,
+            jitter_ms: 500,
+        }
+    }
+}
+
+impl RetryConfig {
+    /// 计算第 `attempt` 次尝试失败后的等待时间（attempt 从 1 开始）
+    pub fn delay_for(&self, attempt: u32) -> Duration {
+        let exponent = attempt.saturating_sub(1).min(16);
+        let backoff = Duration::from_millis(self.base_delay_ms.saturating_mul(1 << exponent));
+        let jitter = Duration::from_millis(fastrand::u64(0..=self.jitter_ms));
+        backoff.min(MAX_DELAY) + jitter
+    }
+
+    /// 服务器通过 Retry-After 要求等待时取两者中较长的，但不超过 MAX_DELAY，
+    /// 避免异常的 Retry-After 让上传长时间停滞
+    pub fn delay_after(&self, attempt: u32, retry_after: Option<Duration>) -> Duration {
+        retry_after
+            .unwrap_or_default()
+            .min(MAX_DELAY)
+            .max(self.delay_for(attempt))
+    }
+}
+
+/// 判断响应状态码是否值得重试：请求超时、限流和服务端错误
+pub fn is_retryable_status(status: StatusCode) -> bool {
+    status == StatusCode::REQUEST_TIMEOUT
+        || status == StatusCode::TOO_MANY_REQUESTS
+        || status.is_server_error()
+}
+
+/// 判断请求错误是否值得重试：连接失败、超时以及传输中断
+pub fn is_retryable_error(error: &reqwest::Error) -> bool {
+    error.is_connect() || error.is_timeout() || error.is_request() || error.is_body()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(base_delay_ms: u64) -> RetryConfig {
+        RetryConfig {
+            max_attempts: 5,
+            base_delay_ms,
+            jitter_ms: 0,
+        }
+    }
+
+    #[test]
+    fn delay_grows_exponentially() {
+        let retry = config(1000);
+        assert_eq!(retry.delay_for(1), Duration::from_secs(1));
+        assert_eq!(retry.delay_for(2), Duration::from_secs(2));
+        assert_eq!(retry.delay_for(3), Duration::from_secs(4));
+        // attempt 从 1 开始，0 按第一次处理
+        assert_eq!(retry.delay_for(0), Duration::from_secs(1));
+    }
+
+    #[test]
+    fn delay_is_capped() {
+        assert_eq!(config(1000).delay_for(10), MAX_DELAY);
+        assert_eq!(config(1000).delay_for(u32::MAX), MAX_DELAY);
+        assert_eq!(config(u64::MAX).delay_for(20), MAX_DELAY);
+    }
+
+    #[test]
+    fn jitter_stays_within_limit() {
+        let retry = RetryConfig {
+            jitter_ms: 500,
+            ..config(1000)
+        };
+        for _ in 0..100 {
+            let delay = retry.delay_for(1);
+            assert!(delay >= Duration::from_secs(1));
+            assert!(delay <= Duration::from_millis(1500));
+        }
+    }
+
+    #[test]
+    fn retry_after_is_clamped() {
+        let retry = config(1000);
+        assert_eq!(retry.delay_after(1, None), Duration::from_secs(1));
+        assert_eq!(
+            retry.delay_after(1, Some(Duration::from_secs(30))),
+            Duration::from_secs(30)
+        );
+        // 比退避时间短时使用退避时间
+        assert_eq!(
+            retry.delay_after(3, Some(Duration::from_secs(1))),
+            Duration::from_secs(4)
+        );
+        assert_eq!(
+            retry.delay_after(1, Some(Duration::from_secs(86_400))),
+            MAX_DELAY
+        );
+    }
+
+    #[test]
+    fn retryable_statuses() {
+        for status in [408, 429, 500, 502, 503, 504, 507] {
+            assert!(
+                is_retryable_status(StatusCode::from_u16(status).unwrap()),
+                "{status}"
+            );
+        }
+        for status in [200, 400, 401, 403, 404, 409, 413, 415, 422] {
+            assert!(
+                !is_retryable_status(StatusCode::from_u16(status).unwrap()),
+                "{status}"
+            );
+        }
+    }
+}
@@ -0,0 +1,345 @@
+use aes_gcm::aead::rand_core::RngCore;
+use aes_gcm::aead::{Aead, KeyInit, OsRng};
+use aes_gcm::{Aes256Gcm, Nonce};
+use anyhow::{Context, Result};
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::{Mutex, OnceLock};
+
+/// 系统密钥环中使用的服务名
+const KEYRING_SERVICE: &str = "rimmich";
+
+/// 加密文件后端的口令环境变量
+pub const PASSPHRASE_ENV: &str = "RIMMICH_SECRETS_PASSPHRASE";
+
+const SALT_LEN: usize = 16;
+const NONCE_LEN: usize = 12;
+
+/// 密钥存储后端
+pub trait SecretStore: Send + Sync {
+    /// 后端名称，作为配置文件中引用的前缀
+    fn name(&self) -> &'static str;
+    fn get(&self, account: &str) -> Result<Option<String>>;
+    fn set(&self, account: &str, secret: &str) -> Result<()>;
+    fn delete(&self, account: &str) -> Result<()>;
+}
+
+/// 系统密钥环：macOS Keychain、Windows 凭据管理器或 Linux Secret Service
+struct KeyringStore;
+
+impl KeyringStore {
+    /// 检查密钥环是否可用，无桌面会话的 Linux 上通常不可用
+    fn probe() -> Option<Self> {
+        let entry = keyring::Entry::new(KEYRING_SERVICE, "__probe__").ok()?;
+        match entry.get_password() {
+            Ok(_) | Err(keyring::Error::NoEntry) => Some(Self),
+            Err(e) => {
+                tracing::warn!("System keyring is not available: {}", e);
+                None
+            }
+        }
+    }
+}
+
+impl SecretStore for KeyringStore {
+    fn name(&self) -> &'static str {
+        "keyring"
+    }
+
+    fn get(&self, account: &str) -> Result<Option<String>> {
+        let entry = keyring::Entry::new(KEYRING_SERVICE, account)
+            .context("Failed to open keyring entry")?;
+        match entry.get_password() {
+            Ok(secret) => Ok(Some(secret)),
+            Err(keyring::Error::NoEntry) => Ok(None),
+            Err(e) => Err(anyhow::Error::new(e).context("Failed to read secret from keyring")),
+        }
+    }
+
+    fn set(&self, account: &str, secret: &str) -> Result<()> {
+        keyring::Entry::new(KEYRING_SERVICE, account)
+            .context("Failed to open keyring entry")?
+            .set_password(secret)
+            .context("Failed to write secret to keyring")
+    }
+
+    fn delete(&self, account: &str) -> Result<()> {
+        let entry = keyring::Entry::new(KEYRING_SERVICE, account)
+            .context("Failed to open keyring entry")?;
+        match entry.delete_credential() {
+            Ok(()) | Err(keyring::Error::NoEntry) => Ok(()),
+            Err(e) => Err(anyhow::Error::new(e).context("Failed to delete secret from keyring")),
+        }
+    }
+}
+
+/// 加密文件的内容
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct SecretFile {
+    /// 派生密钥使用的盐
+    salt: String,
+    /// 账户 -> base64(nonce + 密文)
+    #[serde(default)]
+    secrets: BTreeMap<String, String>,
+}
+
+/// 使用口令加密的本地文件，密钥环不可用时的备选方案
+struct EncryptedFileStore {
+    path: PathBuf,
+    passphrase: String,
+    /// 按盐缓存派生的密钥，Argon2 派生较慢；同时避免并发读写同一个文件
+    cache: Mutex<Option<(String, Aes256Gcm)>>,
+}
+
+impl EncryptedFileStore {
+    fn new(path: PathBuf, passphrase: String) -> Self {
+        Self {
+            path,
+            passphrase,
+            cache: Mutex::new(None),
+        }
+    }
+
+    /// 使用口令环境变量打开 `~/.immich/secrets.toml`，未设置口令时返回 None
+    fn from_env() -> Option<Self> {
+        let passphrase = std::env::var(PASSPHRASE_ENV)
+            .ok()
+            .filter(|passphrase| !passphrase.is_empty())?;
+        match dirs::home_dir() {
+            Some(home) => Some(Self::new(
+                home.join(".immich").join("secrets.toml"),
+                passphrase,
+            )),
+            None => {
+                tracing::warn!("Encrypted secret file is not available: no home directory");
+                None
+            }
+        }
+    }
+
+    fn load(&self) -> Result<SecretFile> {
+        if !self.path.exists() {
+            let mut salt = [0; SALT_LEN];
+            OsRng.fill_bytes(&mut salt);
+            return Ok(SecretFile {
+                salt: BASE64.encode(salt),
+                secrets: BTreeMap::new(),
+            });
+        }
+        let content = fs::read_to_string(&self.path).context("Failed to read secrets file")?;
+        toml::from_str(&content).context("Failed to parse secrets file")
+    }
+
+    fn save(&self, file: &SecretFile) -> Result<()> {
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir).context("Failed to create config directory")?;
+        }
+        let content = toml::to_string(file).context("Failed to serialize secrets file")?;
+        fs::write(&self.path, content).context("Failed to write secrets file")?;
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            fs::set_permissions(&self.path, fs::Permissions::from_mode(0o600))
+                .context("Failed to set secrets file permissions")?;
+        }
+        Ok(())
+    }
+
+    fn cipher(&self, cached: &mut Option<(String, Aes256Gcm)>, salt: &str) -> Result<Aes256Gcm> {
+        if let Some((cached_salt, cipher)) = cached.as_ref()
+            && cached_salt == salt
+        {
+            return Ok(cipher.clone());
+        }
+        let salt_bytes = BASE64
+            .decode(salt)
+            .context("Invalid salt in secrets file")?;
+        let mut key = [0; 32];
+        argon2::Argon2::default()
+            .hash_password_into(self.passphrase.as_bytes(), &salt_bytes, &mut key)
+            .map_err(|e| anyhow::anyhow!("Failed to derive key: {}", e))?;
+        let cipher = Aes256Gcm::new(&key.into());
+        *cached = Some((salt.to_string(), cipher.clone()));
+        Ok(cipher)
+    }
+}
+
+impl SecretStore for EncryptedFileStore {
+    fn name(&self) -> &'static str {
+        "file"
+    }
+
+    fn get(&self, account: &str) -> Result<Option<String>> {
+        let mut cached = self.cache.lock().unwrap_or_else(|e| e.into_inner());
+        let file = self.load()?;
+        let Some(encoded) = file.secrets.get(account) else {
+            return Ok(None);
+        };
+        let data = BASE64
+            .decode(encoded)
+            .context("Invalid secret in secrets file")?;
+        if data.len() < NONCE_LEN {
+            anyhow::bail!("Invalid secret in secrets file");
+        }
+        let (nonce, ciphertext) = data.split_at(NONCE_LEN);
+        let plaintext = self
+            .cipher(&mut cached, &file.salt)?
+            .decrypt(Nonce::from_slice(nonce), ciphertext)
+            .map_err(|_| anyhow::anyhow!("Failed to decrypt secret, wrong passphrase?"))?;
+        Ok(Some(
+            String::from_utf8(plaintext).context("Secret is not valid UTF-8")?,
+        ))
+    }
+
+    fn set(&self, account: &str, secret: &str) -> Result<()> {
+        let mut cached = self.cache.lock().unwrap_or_else(|e| e.into_inner());
+        let mut file = self.load()?;
+        let mut nonce = [0; NONCE_LEN];
+        OsRng.fill_bytes(&mut nonce);
+        let ciphertext = self
+            .cipher(&mut cached, &file.salt)?
+            .encrypt(Nonce::from_slice(&nonce), secret.as_bytes())
+            .map_err(|_| anyhow::anyhow!("Failed to encrypt secret"))?;
+        let mut data = nonce.to_vec();
+        data.extend(ciphertext);
+        file.secrets
+            .insert(account.to_string(), BASE64.encode(data));
+        self.save(&file)
+    }
+
+    fn delete(&self, account: &str) -> Result<()> {
+        let _guard = self.cache.lock().unwrap_or_else(|e| e.into_inner());
+        let mut file = self.load()?;
+        if file.secrets.remove(account).is_some() {
+            self.save(&file)?;
+        }
+        Ok(())
+    }
+}
+
+/// 系统密钥环，首次使用时检查是否可用
+fn keyring_store() -> Option<&'static KeyringStore> {
+    static STORE: OnceLock<Option<KeyringStore>> = OnceLock::new();
+    STORE.get_or_init(KeyringStore::probe).as_ref()
+}
+
+/// 加密文件，只有设置了口令环境变量时可用
+fn file_store() -> Option<&'static EncryptedFileStore> {
+    static STORE: OnceLock<Option<EncryptedFileStore>> = OnceLock::new();
+    STORE.get_or_init(EncryptedFileStore::from_env).as_ref()
+}
+
+/// 保存新 API Key 使用的密钥存储
+///
+/// 设置了口令环境变量时使用加密文件，这样 cron 或 SSH 会话中的命令行工具也能读取桌面端添加的 Key；
+/// 否则使用系统密钥环。都不可用时返回 None，此时无法保存新的 API Key，
+/// 旧版本的明文 API Key 保留到密钥存储可用时再迁移
+pub fn secret_store() -> Option<&'static dyn SecretStore> {
+    if let Some(store) = file_store() {
+        return Some(store);
+    }
+    if let Some(store) = keyring_store() {
+        return Some(store);
+    }
+    static WARNED: OnceLock<()> = OnceLock::new();
+    WARNED.get_or_init(|| {
+        tracing::warn!(
+            "No secret store available, set {} to use an encrypted file",
+            PASSPHRASE_ENV
+        );
+    });
+    None
+}
+
+/// 引用前缀对应的密钥存储，引用格式为 `后端:账户`
+fn store_for(reference: &str) -> Result<(&'static dyn SecretStore, &str)> {
+    let (backend, account) = reference
+        .split_once(':')
+        .with_context(|| format!("Invalid secret reference: {}", reference))?;
+    let store: Option<&'static dyn SecretStore> = match backend {
+        "keyring" => keyring_store().map(|store| store as _),
+        "file" => file_store().map(|store| store as _),
+        _ => anyhow::bail!("Unknown secret store in reference: {}", reference),
+    };
+    match store {
+        Some(store) => Ok((store, account)),
+        None if backend == "file" => anyhow::bail!(
+            "Secret {} is stored in an encrypted file, set {} to read it",
+            account,
+            PASSPHRASE_ENV
+        ),
+        None => anyhow::bail!(
+            "Secret {} is stored in the system keyring, which is not available",
+            account
+        ),
+    }
+}
+
+/// 根据配置中的引用读取密钥
+pub fn resolve_secret(reference: &str) -> Result<Option<String>> {
+    let (store, account) = store_for(reference)?;
+    store.get(account)
+}
+
+/// 根据配置中的引用删除密钥
+pub fn delete_secret(reference: &str) -> Result<()> {
+    let (store, account) = store_for(reference)?;
+    store.delete(account)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::test_util::temp_path;
+
+    #[test]
+    fn file_store_round_trip() {
+        let path = temp_path("secrets-round-trip.toml");
+        let store = EncryptedFileStore::new(path.clone(), "passphrase".to_string());
+
+        assert_eq!(store.get("alice").unwrap(), None);
+        store.set("alice", "secret-a").unwrap();
+        store.set("bob", "secret-b").unwrap();
+        assert_eq!(store.get("alice").unwrap().as_deref(), Some("secret-a"));
+        // 密钥不以明文写入文件
+        let content = fs::read_to_string(&path).unwrap();
+        assert!(!content.contains("secret-a"), "{content}");
+
+        // 重新打开文件后仍可读取
+        let reopened = EncryptedFileStore::new(path.clone(), "passphrase".to_string());
+        assert_eq!(reopened.get("bob").unwrap().as_deref(), Some("secret-b"));
+
+        reopened.delete("alice").unwrap();
+        assert_eq!(reopened.get("alice").unwrap(), None);
+        assert_eq!(reopened.get("bob").unwrap().as_deref(), Some("secret-b"));
+        // 删除不存在的账户不报错
+        reopened.delete("alice").unwrap();
+
+        let _ = fs::remove_file(path);
+    }
+
+    #[test]
+    fn file_store_wrong_passphrase() {
+        let path = temp_path("secrets-wrong-passphrase.toml");
+        EncryptedFileStore::new(path.clone(), "right".to_string())
+            .set("alice", "secret")
+            .unwrap();
+
+        let error = EncryptedFileStore::new(path.clone(), "wrong".to_string())
+            .get("alice")
+            .unwrap_err();
+        assert!(error.to_string().contains("decrypt"), "{error:#}");
+
+        let _ = fs::remove_file(path);
+    }
+
+    #[test]
+    fn invalid_reference() {
+        assert!(resolve_secret("no-backend").is_err());
+        assert!(resolve_secret("unknown:alice").is_err());
+    }
+}
@@ -0,0 +1,40 @@
+//! 单元测试共用的辅助函数
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+/// 当前测试进程专用的临时路径，`name` 需在所有测试中唯一；已存在的文件或目录会被删除
+pub fn temp_path(name: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("rimmich-{}-{}", std::process::id(), name));
+    if path.is_dir() {
+        let _ = fs::remove_dir_all(&path);
+    } else {
+        let _ = fs::remove_file(&path);
+    }
+    path
+}
+
+/// 新建空的临时目录
+pub fn temp_dir(name: &str) -> PathBuf {
+    let dir = temp_path(name);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+/// 写入文件，父目录不存在时自动创建
+pub fn write_file(path: &Path, content: impl AsRef<[u8]>) -> PathBuf {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).unwrap();
+    }
+    fs::write(path, content).unwrap();
+    path.to_path_buf()
+}
+
+/// 修改文件的修改时间
+pub fn set_modified(path: &Path, time: SystemTime) {
+    fs::File::options()
+        .write(true)
+        .open(path)
+        .and_then(|file| file.set_modified(time))
+        .unwrap();
+}
@@ -0,0 +1,144 @@
+use chrono::{Local, NaiveTime};
+use serde::{Deserialize, Serialize};
+use std::sync::{LazyLock, Mutex};
+use std::time::{Duration, Instant};
+
+/// 上传限速设置
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct BandwidthConfig {
+    /// 所有上传共享的速率上限（KB/s），0 表示不限速
+    #[serde(default)]
+    pub limit_kbps: u32,
+    /// 不限速的时间段（本地时间）
+    #[serde(default)]
+    pub unlimited_window: Option<TimeWindow>,
+}
+
+/// 每天的时间段，结束时间早于开始时间表示跨越午夜
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+pub struct TimeWindow {
+    pub start: NaiveTime,
+    pub end: NaiveTime,
+}
+
+impl TimeWindow {
+    pub fn contains(&self, time: NaiveTime) -> bool {
+        if self.start <= self.end {
+            self.start <= time && time < self.end
+        } else {
+            time >= self.start || time < self.end
+        }
+    }
+}
+
+impl BandwidthConfig {
+    /// 指定时间的速率上限（字节/秒），不限速时返回 None
+    pub fn limit_at(&self, time: NaiveTime) -> Option<u64> {
+        if self.limit_kbps == 0
+            || self
+                .unlimited_window
+                .is_some_and(|window| window.contains(time))
+        {
+            None
+        } else {
+            Some(self.limit_kbps as u64 * 1024)
+        }
+    }
+}
+
+/// 全局限速器，所有并发上传按发送顺序依次占用带宽
+struct RateLimiter {
+    config: BandwidthConfig,
+    /// 下一块数据最早可以发送的时间
+    next_send: Instant,
+}
+
+static LIMITER: LazyLock<Mutex<RateLimiter>> = LazyLock::new(|| {
+    Mutex::new(RateLimiter {
+        config: BandwidthConfig::default(),
+        next_send: Instant::now(),
+    })
+});
+
+/// 更新限速设置，对正在进行的上传立即生效
+pub fn set_bandwidth(config: &BandwidthConfig) {
+    let mut limiter = LIMITER.lock().unwrap_or_else(|e| e.into_inner());
+    if limiter.config != *config {
+        tracing::info!("Upload bandwidth limit updated: {:?}", config);
+        limiter.config = config.clone();
+        limiter.next_send = Instant::now();
+    }
+}
+
+/// 发送 `bytes` 字节前调用，超出速率上限时等待
+pub(crate) async fn throttle(bytes: usize) {
+    let delay = {
+        let mut limiter = LIMITER.lock().unwrap_or_else(|e| e.into_inner());
+        let Some(rate) = limiter.config.limit_at(Local::now().time()) else {
+            return;
+        };
+        let now = Instant::now();
+        let start = limiter.next_send.max(now);
+        limiter.next_send = start + Duration::from_secs_f64(bytes as f64 / rate as f64);
+        start - now
+    };
+    if !delay.is_zero() {
+        tokio::time::sleep(delay).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn time(hour: u32, minute: u32) -> NaiveTime {
+        NaiveTime::from_hms_opt(hour, minute, 0).unwrap()
+    }
+
+    fn window(start: NaiveTime, end: NaiveTime) -> TimeWindow {
+        TimeWindow { start, end }
+    }
+
+    #[test]
+    fn window_within_day() {
+        let window = window(time(9, 0), time(17, 0));
+        assert!(!window.contains(time(8, 59)));
+        // 包含开始时间，不包含结束时间
+        assert!(window.contains(time(9, 0)));
+        assert!(window.contains(time(12, 0)));
+        assert!(!window.contains(time(17, 0)));
+    }
+
+    #[test]
+    fn window_across_midnight() {
+        let window = window(time(23, 0), time(7, 0));
+        assert!(!window.contains(time(22, 59)));
+        assert!(window.contains(time(23, 0)));
+        assert!(window.contains(time(0, 0)));
+        assert!(window.contains(time(6, 59)));
+        assert!(!window.contains(time(7, 0)));
+        assert!(!window.contains(time(12, 0)));
+    }
+
+    #[test]
+    fn limit_follows_window() {
+        let config = BandwidthConfig {
+            limit_kbps: 500,
+            unlimited_window: Some(window(time(23, 0), time(7, 0))),
+        };
+        assert_eq!(config.limit_at(time(12, 0)), Some(500 * 1024));
+        assert_eq!(config.limit_at(time(23, 0)), None);
+        assert_eq!(config.limit_at(time(3, 0)), None);
+        assert_eq!(config.limit_at(time(7, 0)), Some(500 * 1024));
+    }
+
+    #[test]
+    fn zero_limit_is_unlimited() {
+        let config = BandwidthConfig {
+            limit_kbps: 0,
+            unlimited_window: None,
+        };
+        assert_eq!(config.limit_at(time(12, 0)), None);
+        assert_eq!(BandwidthConfig::default().limit_at(time(0, 0)), None);
+    }
+}
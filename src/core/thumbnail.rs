@@ -0,0 +1,210 @@
+use crate::core::media::find_sidecar;
+use crate::core::metadata::read_capture_time;
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use image::codecs::jpeg::JpegEncoder;
+use image::{DynamicImage, ImageDecoder, ImageReader};
+use sha1::{Digest, Sha1};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// 缩略图最大边长（像素）
+const THUMBNAIL_SIZE: u32 = 240;
+
+/// 缩略图缓存目录的大小上限，超出时删除最久未使用的缩略图
+const CACHE_LIMIT_BYTES: u64 = 200 * 1024 * 1024;
+
+/// 超过该时间未使用的缩略图会被删除，例如已移动或修改过的文件的缩略图
+const CACHE_MAX_AGE: Duration = Duration::from_secs(30 * 24 * 60 * 60);
+
+/// 可以生成缩略图的文件扩展名（小写）
+const THUMBNAIL_EXTENSIONS: &[&str] = &["jpg", "jpeg", "png", "webp"];
+
+/// 文件预览信息
+#[derive(Debug, Clone, PartialEq)]
+pub struct FilePreview {
+    pub size: u64,
+    pub captured: DateTime<Utc>,
+    /// JPEG 格式的缩略图，视频和 HEIC 等格式为 None
+    pub thumbnail: Option<Vec<u8>>,
+    /// 是否有随文件上传的 XMP 元数据文件
+    pub has_sidecar: bool,
+}
+
+/// 缩略图缓存目录 (~/.immich/thumbnails)
+fn thumbnail_dir() -> Result<PathBuf> {
+    let home = dirs::home_dir().context("Failed to get home directory")?;
+    Ok(home.join(".immich").join("thumbnails"))
+}
+
+fn supports_thumbnail(path: &Path) -> bool {
+    path.extension()
+        .and_then(|ext| ext.to_str())
+        .is_some_and(|ext| THUMBNAIL_EXTENSIONS.contains(&ext.to_ascii_lowercase().as_str()))
+}
+
+/// 读取文件大小、拍摄时间、缩略图和 XMP 文件，缩略图生成失败时只记录日志
+pub fn load_preview(path: &Path) -> Result<FilePreview> {
+    let metadata = fs::metadata(path).context("Failed to read file metadata")?;
+    let modified = metadata.modified().unwrap_or(UNIX_EPOCH);
+    let captured = read_capture_time(path, modified).time;
+
+    let thumbnail = if supports_thumbnail(path) {
+        let modified_ms = modified
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.as_millis())
+            .unwrap_or_default();
+        cached_thumbnail(path, metadata.len(), modified_ms)
+            .inspect_err(|e| tracing::warn!("Failed to create thumbnail for {:?}: {:#}", path, e))
+            .ok()
+    } else {
+        None
+    };
+
+    Ok(FilePreview {
+        size: metadata.len(),
+        captured,
+        thumbnail,
+        has_sidecar: find_sidecar(path).is_some(),
+    })
+}
+
+/// 读取缓存的缩略图，文件大小或修改时间变化后会重新生成
+fn cached_thumbnail(path: &Path, size: u64, modified_ms: u128) -> Result<Vec<u8>> {
+    let mut hasher = Sha1::new();
+    hasher.update(path.to_string_lossy().as_bytes());
+    hasher.update(size.to_le_bytes());
+    hasher.update(modified_ms.to_le_bytes());
+    let cache_path = thumbnail_dir()?.join(format!("{:x}.jpg", hasher.finalize()));
+
+    if let Ok(data) = fs::read(&cache_path) {
+        // 修改时间记录最近一次使用，清理缓存时按它淘汰
+        if let Err(e) = fs::File::options()
+            .write(true)
+            .open(&cache_path)
+            .and_then(|file| file.set_modified(SystemTime::now()))
+        {
+            tracing::debug!("Failed to touch thumbnail {:?}: {}", cache_path, e);
+        }
+        return Ok(data);
+    }
+
+    let data = generate_thumbnail(path)?;
+    if let Some(dir) = cache_path.parent() {
+        fs::create_dir_all(dir).context("Failed to create thumbnail directory")?;
+    }
+    if let Err(e) = fs::write(&cache_path, &data) {
+        tracing::warn!("Failed to cache thumbnail {:?}: {}", cache_path, e);
+    }
+    Ok(data)
+}
+
+/// 清理缩略图缓存：删除长期未使用的缩略图，并在超出大小上限时删除最久未使用的缩略图
+pub fn prune_thumbnail_cache() -> Result<()> {
+    prune_cache(&thumbnail_dir()?, CACHE_LIMIT_BYTES, CACHE_MAX_AGE)
+}
+
+fn prune_cache(dir: &Path, limit_bytes: u64, max_age: Duration) -> Result<()> {
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(()),
+        Err(e) => return Err(e).context("Failed to read thumbnail directory"),
+    };
+    let mut files: Vec<(SystemTime, u64, PathBuf)> = entries
+        .flatten()
+        .filter_map(|entry| {
+            let metadata = entry
+                .metadata()
+                .ok()
+                .filter(|metadata| metadata.is_file())?;
+            let used = metadata.modified().unwrap_or(UNIX_EPOCH);
+            Some((used, metadata.len(), entry.path()))
+        })
+        .collect();
+    // 最久未使用的排在前面
+    files.sort();
+
+    let now = SystemTime::now();
+    let mut total: u64 = files.iter().map(|(_, size, _)| size).sum();
+    let mut removed = 0;
+    for (used, size, path) in files {
+        let expired = now.duration_since(used).is_ok_and(|age| age > max_age);
+        if !expired && total <= limit_bytes {
+            break;
+        }
+        match fs::remove_file(&path) {
+            Ok(()) => {
+                total -= size;
+                removed += 1;
+            }
+            Err(e) => tracing::warn!("Failed to remove thumbnail {:?}: {}", path, e),
+        }
+    }
+    if removed > 0 {
+        tracing::info!(
+            "Removed {} cached thumbnails, {} bytes remaining",
+            removed,
+            total
+        );
+    }
+    Ok(())
+}
+
+/// 按 EXIF 方向旋转后缩放，编码为 JPEG
+fn generate_thumbnail(path: &Path) -> Result<Vec<u8>> {
+    let mut decoder = ImageReader::open(path)
+        .context("Failed to open image")?
+        .with_guessed_format()
+        .context("Failed to detect image format")?
+        .into_decoder()
+        .context("Failed to create image decoder")?;
+    let orientation = decoder
+        .orientation()
+        .context("Failed to read image orientation")?;
+    let mut image = DynamicImage::from_decoder(decoder).context("Failed to decode image")?;
+    image.apply_orientation(orientation);
+
+    let thumbnail = image.thumbnail(THUMBNAIL_SIZE, THUMBNAIL_SIZE).into_rgb8();
+    let mut data = Vec::new();
+    JpegEncoder::new_with_quality(&mut data, 80)
+        .encode_image(&thumbnail)
+        .context("Failed to encode thumbnail")?;
+    Ok(data)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::test_util::{set_modified, temp_dir, write_file};
+
+    const DAY: Duration = Duration::from_secs(24 * 60 * 60);
+
+    fn cache_file(dir: &Path, name: &str, size: usize, age: Duration) -> PathBuf {
+        let path = write_file(&dir.join(name), vec![0; size]);
+        set_modified(&path, SystemTime::now() - age);
+        path
+    }
+
+    #[test]
+    fn prunes_expired_and_oldest_thumbnails() {
+        let dir = temp_dir("thumbnails");
+        let expired = cache_file(&dir, "expired.jpg", 10, 40 * DAY);
+        let oldest = cache_file(&dir, "oldest.jpg", 100, 3 * DAY);
+        let older = cache_file(&dir, "older.jpg", 100, 2 * DAY);
+        let recent = cache_file(&dir, "recent.jpg", 100, DAY);
+
+        prune_cache(&dir, 250, 30 * DAY).unwrap();
+        assert!(!expired.exists());
+        assert!(!oldest.exists());
+        assert!(older.exists());
+        assert!(recent.exists());
+
+        // 未超出上限时只删除过期的缩略图
+        prune_cache(&dir, 250, 30 * DAY).unwrap();
+        assert!(older.exists());
+
+        let _ = fs::remove_dir_all(&dir);
+        prune_cache(&dir, 250, 30 * DAY).unwrap();
+    }
+}
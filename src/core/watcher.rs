@@ -0,0 +1,562 @@
+use crate::core::batch::{BatchControl, UploadEvent, UploadJob, UploadReport, run_upload};
+use crate::core::config::{AppConfig, WatchedFolder};
+use crate::core::immich::ImmichClient;
+use crate::core::ledger::UploadLedger;
+use crate::core::media::{collect_media_files, is_supported_media};
+use anyhow::{Context, Result};
+use globset::{Glob, GlobSet, GlobSetBuilder};
+use notify::event::ModifyKind;
+use notify::{Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant, SystemTime};
+use tokio::sync::{mpsc, watch};
+use tokio::task::JoinHandle;
+
+/// 最后一次文件系统事件之后需要等待的时间
+const DEBOUNCE: Duration = Duration::from_secs(2);
+
+/// 检查待处理文件的间隔
+const POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// 监控器产生的事件，供界面展示
+#[derive(Debug, Clone)]
+pub enum WatchEvent {
+    /// 新文件已写入完成，加入上传队列
+    Queued { user: String, count: usize },
+    /// 上传过程中的事件
+    Upload(UploadEvent),
+    /// 一批文件上传结束
+    Finished { user: String, report: UploadReport },
+    /// 无法上传，例如用户已被删除
+    Error { user: String, error: String },
+}
+
+/// 编译后的监控规则
+#[derive(Clone)]
+struct FolderRule {
+    folder: WatchedFolder,
+    /// 规范化后的监控目录，用于匹配事件路径
+    root: PathBuf,
+    include: Option<GlobSet>,
+    exclude: Option<GlobSet>,
+}
+
+impl FolderRule {
+    fn new(folder: WatchedFolder) -> Result<Self> {
+        Ok(Self {
+            root: folder
+                .path
+                .canonicalize()
+                .unwrap_or_else(|_| folder.path.clone()),
+            include: build_glob_set(&folder.include)?,
+            exclude: build_glob_set(&folder.exclude)?,
+            folder,
+        })
+    }
+
+    /// 路径相对于监控目录的部分，不属于该目录时返回 None
+    fn relative<'a>(&self, path: &'a Path) -> Option<&'a Path> {
+        path.strip_prefix(&self.root)
+            .or_else(|_| path.strip_prefix(&self.folder.path))
+            .ok()
+    }
+
+    /// 判断新建的目录是否需要扫描：只有递归监控的规则会包含子目录中的文件
+    fn covers_dir(&self, path: &Path) -> bool {
+        self.folder.recursive && self.relative(path).is_some()
+    }
+
+    /// 列出监控目录中已有的媒体文件
+    fn existing_files(&self) -> Vec<PathBuf> {
+        if self.folder.recursive {
+            return collect_media_files(std::slice::from_ref(&self.folder.path)).files;
+        }
+        let Ok(entries) = std::fs::read_dir(&self.folder.path) else {
+            return Vec::new();
+        };
+        entries
+            .flatten()
+            .map(|entry| entry.path())
+            .filter(|path| path.is_file())
+            .collect()
+    }
+
+    /// 判断文件是否属于该监控目录且符合过滤规则
+    fn matches(&self, path: &Path) -> bool {
+        let Some(relative) = self.relative(path) else {
+            return false;
+        };
+        if !self.folder.recursive && relative.components().count() > 1 {
+            return false;
+        }
+        let is_hidden = relative.components().any(|component| {
+            component
+                .as_os_str()
+                .to_str()
+                .map(|name| name.starts_with('.'))
+                .unwrap_or(false)
+        });
+        if is_hidden || !is_supported_media(path) {
+            return false;
+        }
+        if let Some(include) = &self.include
+            && !include.is_match(relative)
+        {
+            return false;
+        }
+        if let Some(exclude) = &self.exclude
+            && exclude.is_match(relative)
+        {
+            return false;
+        }
+        true
+    }
+}
+
+/// 校验监控文件夹配置：目录必须存在且 glob 语法正确
+pub fn validate_watched_folder(folder: &WatchedFolder) -> Result<()> {
+    if !folder.path.is_dir() {
+        anyhow::bail!("Folder does not exist: {:?}", folder.path);
+    }
+    FolderRule::new(folder.clone()).map(|_| ())
+}
+
+fn build_glob_set(patterns: &[String]) -> Result<Option<GlobSet>> {
+    let patterns: Vec<_> = patterns
+        .iter()
+        .map(|pattern| pattern.trim())
+        .filter(|pattern| !pattern.is_empty())
+        .collect();
+    if patterns.is_empty() {
+        return Ok(None);
+    }
+    let mut builder = GlobSetBuilder::new();
+    for pattern in patterns {
+        builder.add(Glob::new(pattern).with_context(|| format!("Invalid glob: {}", pattern))?);
+    }
+    Ok(Some(builder.build().context("Failed to build glob set")?))
+}
+
+/// 等待写入完成的文件
+struct PendingFile {
+    user: String,
+    last_event: Instant,
+    /// 上一次检查时的大小和修改时间，连续两次一致才认为写入完成
+    last_seen: Option<(u64, SystemTime)>,
+}
+
+/// 交给上传任务的一批文件
+type UploadBatch = (String, Vec<PathBuf>);
+
+/// 后台文件夹监控器，drop 时停止监控和正在进行的上传
+pub struct FolderWatcher {
+    _watcher: RecommendedWatcher,
+    task: JoinHandle<()>,
+    uploader: JoinHandle<()>,
+}
+
+impl Drop for FolderWatcher {
+    fn drop(&mut self) {
+        self.task.abort();
+        self.uploader.abort();
+    }
+}
+
+/// 启动文件夹监控
+///
+/// 新文件在事件静默一段时间且大小不再变化后，按用户分组交给 [`run_upload`] 上传。
+/// 上传时使用 `config` 中最新的服务器地址、API Key 和上传设置。
+pub fn start_watcher(
+    folders: Vec<WatchedFolder>,
+    config: watch::Receiver<AppConfig>,
+    events: mpsc::UnboundedSender<WatchEvent>,
+) -> Result<FolderWatcher> {
+    let rules = folders
+        .into_iter()
+        .map(FolderRule::new)
+        .collect::<Result<Vec<_>>>()?;
+
+    let (fs_tx, fs_rx) = mpsc::unbounded_channel::<Event>();
+    let mut watcher = notify::recommended_watcher(move |res: notify::Result<Event>| match res {
+        Ok(event) => {
+            let _ = fs_tx.send(event);
+        }
+        Err(e) => tracing::warn!("File watcher error: {}", e),
+    })
+    .context("Failed to create file watcher")?;
+
+    for rule in &rules {
+        let mode = if rule.folder.recursive {
+            RecursiveMode::Recursive
+        } else {
+            RecursiveMode::NonRecursive
+        };
+        watcher
+            .watch(&rule.folder.path, mode)
+            .with_context(|| format!("Failed to watch folder: {:?}", rule.folder.path))?;
+        tracing::info!(
+            "Watching folder {:?} for user {} (recursive: {})",
+            rule.folder.path,
+            rule.folder.user,
+            rule.folder.recursive
+        );
+    }
+
+    // 开始监控前已在目录中的文件同样需要上传，本地记录中已上传的文件跳过
+    let (found_tx, found_rx) = mpsc::unbounded_channel();
+    {
+        let rules = rules.clone();
+        let config = config.borrow().clone();
+        let found_tx = found_tx.clone();
+        tokio::task::spawn_blocking(move || {
+            let _ = found_tx.send(scan_existing_files(&rules, &config));
+        });
+    }
+
+    // 上传在单独的任务中依次进行，监控循环不会因上传而停止读取事件
+    let (upload_tx, upload_rx) = mpsc::unbounded_channel();
+    let (done_tx, done_rx) = mpsc::unbounded_channel();
+    let uploader = tokio::spawn(upload_worker(upload_rx, done_tx, config, events));
+    let task = tokio::spawn(watch_loop(
+        rules, fs_rx, found_tx, found_rx, upload_tx, done_rx,
+    ));
+
+    Ok(FolderWatcher {
+        _watcher: watcher,
+        task,
+        uploader,
+    })
+}
+
+/// 列出各监控目录中已有且未在本地记录中标记为已上传的文件
+fn scan_existing_files(rules: &[FolderRule], config: &AppConfig) -> Vec<PathBuf> {
+    let ledger = UploadLedger::load().unwrap_or_else(|e| {
+        tracing::warn!("Failed to load upload ledger: {}", e);
+        UploadLedger::default()
+    });
+    let mut found = Vec::new();
+    for rule in rules {
+        let user = &rule.folder.user;
+        let server = config
+            .server_for_user(user)
+            .map(|server| server.url.trim_end_matches('/').to_string());
+        let files: Vec<_> = rule
+            .existing_files()
+            .into_iter()
+            .filter(|path| rule.matches(path))
+            .filter(|path| {
+                server
+                    .as_deref()
+                    .is_none_or(|server| ledger.lookup(server, user, path).is_none())
+            })
+            .collect();
+        tracing::info!(
+            "Found {} existing files to upload in {:?}",
+            files.len(),
+            rule.folder.path
+        );
+        found.extend(files);
+    }
+    found
+}
+
+/// 将文件加入待处理列表，不属于任何监控规则的文件被忽略
+fn queue_file(rules: &[FolderRule], pending: &mut HashMap<PathBuf, PendingFile>, path: PathBuf) {
+    let Some(rule) = rules.iter().find(|rule| rule.matches(&path)) else {
+        return;
+    };
+    tracing::debug!("Detected file change: {:?}", path);
+    let entry = pending.entry(path).or_insert_with(|| PendingFile {
+        user: rule.folder.user.clone(),
+        last_event: Instant::now(),
+        last_seen: None,
+    });
+    entry.last_event = Instant::now();
+    entry.last_seen = None;
+}
+
+async fn watch_loop(
+    rules: Vec<FolderRule>,
+    mut fs_rx: mpsc::UnboundedReceiver<Event>,
+    found_tx: mpsc::UnboundedSender<Vec<PathBuf>>,
+    mut found_rx: mpsc::UnboundedReceiver<Vec<PathBuf>>,
+    uploads: mpsc::UnboundedSender<UploadBatch>,
+    mut done: mpsc::UnboundedReceiver<Vec<PathBuf>>,
+) {
+    let mut pending: HashMap<PathBuf, PendingFile> = HashMap::new();
+    // 已交给上传任务但尚未完成的文件
+    let mut in_flight: HashSet<PathBuf> = HashSet::new();
+    let mut ticker = tokio::time::interval(POLL_INTERVAL);
+
+    loop {
+        tokio::select! {
+            event = fs_rx.recv() => {
+                let Some(event) = event else { break };
+                if !matches!(event.kind, EventKind::Create(_) | EventKind::Modify(_)) {
+                    continue;
+                }
+                let is_added = matches!(
+                    event.kind,
+                    EventKind::Create(_) | EventKind::Modify(ModifyKind::Name(_))
+                );
+                for path in event.paths {
+                    // 新建或移入的目录在监控生效前可能已经写入了文件，需要扫描一次
+                    if is_added && path.is_dir() {
+                        if rules.iter().any(|rule| rule.covers_dir(&path)) {
+                            tracing::debug!("Scanning new folder: {:?}", path);
+                            let found_tx = found_tx.clone();
+                            tokio::task::spawn_blocking(move || {
+                                let _ = found_tx.send(collect_media_files(&[path]).files);
+                            });
+                        }
+                        continue;
+                    }
+                    queue_file(&rules, &mut pending, path);
+                }
+            }
+            Some(paths) = found_rx.recv() => {
+                for path in paths {
+                    queue_file(&rules, &mut pending, path);
+                }
+            }
+            Some(paths) = done.recv() => {
+                for path in paths {
+                    in_flight.remove(&path);
+                }
+            }
+            _ = ticker.tick() => {
+                let ready = take_ready_files(&mut pending, &in_flight);
+                for (user, files) in ready {
+                    in_flight.extend(files.iter().cloned());
+                    if uploads.send((user, files)).is_err() {
+                        tracing::error!("Watched folder upload task stopped");
+                        return;
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// 依次上传监控循环交来的文件，完成后通知监控循环
+async fn upload_worker(
+    mut uploads: mpsc::UnboundedReceiver<UploadBatch>,
+    done: mpsc::UnboundedSender<Vec<PathBuf>>,
+    config: watch::Receiver<AppConfig>,
+    events: mpsc::UnboundedSender<WatchEvent>,
+) {
+    while let Some((user, files)) = uploads.recv().await {
+        upload_files(&user, files.clone(), &config, &events).await;
+        let _ = done.send(files);
+    }
+}
+
+/// 取出已写入完成的文件，按用户分组
+///
+/// 正在上传的文件保留在待处理列表中，上传结束后再处理，避免同一文件被重复上传
+fn take_ready_files(
+    pending: &mut HashMap<PathBuf, PendingFile>,
+    in_flight: &HashSet<PathBuf>,
+) -> BTreeMap<String, Vec<PathBuf>> {
+    let mut ready: BTreeMap<String, Vec<PathBuf>> = BTreeMap::new();
+    pending.retain(|path, file| {
+        if file.last_event.elapsed() < DEBOUNCE || in_flight.contains(path) {
+            return true;
+        }
+        let Ok(metadata) = std::fs::metadata(path) else {
+            // 文件已被删除或移走
+            return false;
+        };
+        let current = (
+            metadata.len(),
+            metadata.modified().unwrap_or(SystemTime::UNIX_EPOCH),
+        );
+        if file.last_seen == Some(current) {
+            ready
+                .entry(file.user.clone())
+                .or_default()
+                .push(path.clone());
+            false
+        } else {
+            file.last_seen = Some(current);
+            true
+        }
+    });
+    ready
+}
+
+async fn upload_files(
+    user: &str,
+    mut files: Vec<PathBuf>,
+    config: &watch::Receiver<AppConfig>,
+    events: &mpsc::UnboundedSender<WatchEvent>,
+) {
+    files.sort();
+    let job = {
+        let config = config.borrow();
+        let (Some(user_info), Some(server)) =
+            (config.users.get(user), config.server_for_user(user))
+        else {
+            tracing::warn!("Watched folder user not found: {}", user);
+            let _ = events.send(WatchEvent::Error {
+                user: user.to_string(),
+                error: "API Key not found for user".to_string(),
+            });
+            return;
+        };
+        let client = match user_info
+            .require_api_key()
+            .and_then(|api_key| ImmichClient::new(server, api_key))
+        {
+            Ok(client) => client,
+            Err(e) => {
+                tracing::warn!("Failed to create client for user {}: {:#}", user, e);
+                let _ = events.send(WatchEvent::Error {
+                    user: user.to_string(),
+                    error: format!("{:#}", e),
+                });
+                return;
+            }
+        };
+        UploadJob {
+            client,
+            user: user.to_string(),
+            concurrency: config.concurrency as usize,
+            files,
+            continue_on_error: true,
+            retry: config.retry.clone(),
+            album_id: None,
+            force: false,
+            control: BatchControl::default(),
+        }
+    };
+
+    tracing::info!(
+        "Auto uploading {} new files for user {}",
+        job.files.len(),
+        user
+    );
+    let _ = events.send(WatchEvent::Queued {
+        user: user.to_string(),
+        count: job.files.len(),
+    });
+
+    // 在上传任务中直接等待上传结束，停止监控时正在进行的上传会一起中止
+    let (tx, mut rx) = mpsc::unbounded_channel();
+    let forward = async {
+        while let Some(event) = rx.recv().await {
+            let _ = events.send(WatchEvent::Upload(event));
+        }
+    };
+    let (report, ()) = tokio::join!(run_upload(job, tx), forward);
+    let _ = events.send(WatchEvent::Finished {
+        user: user.to_string(),
+        report,
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::test_util::{temp_dir, write_file};
+
+    fn folder(path: &Path, recursive: bool, include: &[&str], exclude: &[&str]) -> WatchedFolder {
+        WatchedFolder {
+            path: path.to_path_buf(),
+            user: "alice".to_string(),
+            recursive,
+            include: include.iter().map(|glob| glob.to_string()).collect(),
+            exclude: exclude.iter().map(|glob| glob.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn matches_supported_files_under_folder() {
+        let root = Path::new("/photos");
+        let rule = FolderRule::new(folder(root, true, &[], &[])).unwrap();
+        assert!(rule.matches(&root.join("a.jpg")));
+        assert!(rule.matches(&root.join("2024/trip/b.MOV")));
+        assert!(!rule.matches(&root.join("notes.txt")));
+        assert!(!rule.matches(&root.join(".hidden.jpg")));
+        assert!(!rule.matches(&root.join(".cache/c.jpg")));
+        assert!(!rule.matches(Path::new("/other/a.jpg")));
+    }
+
+    #[test]
+    fn non_recursive_only_matches_top_level() {
+        let root = Path::new("/photos");
+        let rule = FolderRule::new(folder(root, false, &[], &[])).unwrap();
+        assert!(rule.matches(&root.join("a.jpg")));
+        assert!(!rule.matches(&root.join("sub/a.jpg")));
+        assert!(!rule.covers_dir(&root.join("sub")));
+
+        let recursive = FolderRule::new(folder(root, true, &[], &[])).unwrap();
+        assert!(recursive.covers_dir(&root.join("sub")));
+        assert!(!recursive.covers_dir(Path::new("/other")));
+    }
+
+    #[test]
+    fn include_and_exclude_globs() {
+        let root = Path::new("/photos");
+        let rule =
+            FolderRule::new(folder(root, true, &["**/*.jpg", "*.heic"], &["raw/**"])).unwrap();
+        assert!(rule.matches(&root.join("a.jpg")));
+        assert!(rule.matches(&root.join("2024/a.jpg")));
+        assert!(rule.matches(&root.join("b.heic")));
+        assert!(!rule.matches(&root.join("c.mp4")));
+        assert!(!rule.matches(&root.join("raw/a.jpg")));
+
+        // 空白的规则被忽略
+        let rule = FolderRule::new(folder(root, true, &[" "], &[""])).unwrap();
+        assert!(rule.matches(&root.join("c.mp4")));
+
+        assert!(FolderRule::new(folder(root, true, &["[a"], &[])).is_err());
+    }
+
+    fn pending_file(age: Duration) -> PendingFile {
+        PendingFile {
+            user: "alice".to_string(),
+            last_event: Instant::now().checked_sub(age).unwrap(),
+            last_seen: None,
+        }
+    }
+
+    #[test]
+    fn ready_after_size_settles() {
+        let dir = temp_dir("watcher-settle");
+        let settled = write_file(&dir.join("settled.jpg"), b"photo");
+        let growing = write_file(&dir.join("growing.jpg"), b"pho");
+        let recent = write_file(&dir.join("recent.jpg"), b"photo");
+        let uploading = write_file(&dir.join("uploading.jpg"), b"photo");
+        let deleted = dir.join("deleted.jpg");
+
+        let mut pending = HashMap::new();
+        for path in [&settled, &growing, &uploading, &deleted] {
+            pending.insert(path.clone(), pending_file(DEBOUNCE * 2));
+        }
+        pending.insert(recent.clone(), pending_file(Duration::ZERO));
+        let in_flight = HashSet::from([uploading.clone()]);
+
+        // 第一次检查只记录大小，已删除的文件被移除
+        assert!(take_ready_files(&mut pending, &in_flight).is_empty());
+        assert!(!pending.contains_key(&deleted));
+
+        write_file(&growing, b"photo, still copying");
+        let ready = take_ready_files(&mut pending, &in_flight);
+        assert_eq!(
+            ready,
+            BTreeMap::from([("alice".to_string(), vec![settled])])
+        );
+        assert_eq!(
+            pending.keys().cloned().collect::<HashSet<_>>(),
+            HashSet::from([growing.clone(), recent, uploading])
+        );
+
+        let ready = take_ready_files(&mut pending, &in_flight);
+        assert_eq!(
+            ready,
+            BTreeMap::from([("alice".to_string(), vec![growing])])
+        );
+    }
+}
@@ -1,2 +1,3 @@
+#[cfg(feature = "desktop")]
 pub mod components;
 pub mod core;
@@ -1,6 +1,6 @@
 use dioxus::{LaunchBuilder, desktop};
 use rimmich::components::App;
-use rimmich::core::{AppConfig, init_logger, load_config};
+use rimmich::core::{AppConfig, init_logger, load_config, prune_thumbnail_cache, set_bandwidth};
 
 fn main() {
     // 加载配置文件，失败时使用默认配置
@@ -13,6 +13,14 @@ fn main() {
     init_logger(&config.log_level).unwrap_or_else(|e| {
         eprintln!("Failed to initialize logger: {}", e);
     });
+    set_bandwidth(&config.bandwidth);
+
+    // 在后台清理缩略图缓存，不阻塞窗口启动
+    std::thread::spawn(|| {
+        if let Err(e) = prune_thumbnail_cache() {
+            tracing::warn!("Failed to prune thumbnail cache: {:#}", e);
+        }
+    });
 
     // 启动桌面应用
     LaunchBuilder::desktop()